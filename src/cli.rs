@@ -0,0 +1,278 @@
+use clap::{Parser, Subcommand};
+use serde_json::Value;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use tracing::{debug, error, info};
+
+use crate::mcp::types::{ToolCallResult, ToolContent};
+use crate::planka::backup::ProjectBackup;
+use crate::planka::spec::BoardSpec;
+use crate::planka::ClientRegistry;
+use crate::tools;
+
+/// MCP server for Planka kanban boards
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct Cli {
+    /// Path to a TOML configuration file with named profiles
+    #[arg(long, global = true, env = "PLANKA_CONFIG")]
+    pub config: Option<PathBuf>,
+
+    /// Profile to use from the configuration file
+    #[arg(long, global = true, env = "PLANKA_PROFILE")]
+    pub profile: Option<String>,
+
+    /// Additional profiles to serve as separate instances (comma-separated)
+    #[arg(long, global = true, env = "PLANKA_INSTANCES", value_delimiter = ',')]
+    pub instances: Vec<String>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run the MCP server over stdio (default)
+    Serve {
+        /// Listen for MCP over HTTP on this address instead, with each session
+        /// authenticated by its own Planka token
+        #[arg(long, env = "PLANKA_MCP_HTTP")]
+        http: Option<SocketAddr>,
+    },
+    /// Verify connectivity and authentication for every configured instance
+    Check,
+    /// List the available tools
+    Tools {
+        /// Print the full tool definitions as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Invoke a tool and print its result
+    Call {
+        /// Name of the tool to call
+        tool: String,
+        /// Tool arguments as a JSON object
+        #[arg(long)]
+        args: Option<String>,
+        /// Print the raw tool result as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Reconcile a board toward a YAML or JSON spec file, printing the changes first
+    Apply {
+        /// Path to the board spec
+        file: PathBuf,
+        /// Only print the changes
+        #[arg(long)]
+        dry_run: bool,
+        /// Delete lists (with their cards) and labels missing from the spec
+        #[arg(long)]
+        prune: bool,
+        /// Instance holding the board (default: the default instance)
+        #[arg(long)]
+        instance: Option<String>,
+    },
+    /// Write a JSON backup of a project with all its boards
+    Backup {
+        /// The project ID to back up
+        project_id: String,
+        /// File to write the backup to (default: stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Instance holding the project (default: the default instance)
+        #[arg(long)]
+        instance: Option<String>,
+    },
+    /// Recreate a project from a backup file
+    Restore {
+        /// Path to the backup
+        file: PathBuf,
+        /// Name of the restored project (default: the name in the backup)
+        #[arg(long)]
+        name: Option<String>,
+        /// Instance to restore into (default: the default instance)
+        #[arg(long)]
+        instance: Option<String>,
+    },
+}
+
+/// Checks every instance by listing its projects, which requires a valid login.
+/// Returns whether all instances are reachable.
+pub async fn check(registry: &ClientRegistry) -> bool {
+    let mut ok = true;
+    for (name, client) in registry.iter() {
+        debug!(instance = %name, "Checking Planka instance");
+        match client.list_projects().await {
+            Ok(projects) => {
+                info!(instance = %name, "Instance check passed");
+                println!(
+                    "ok    {name} ({}): authenticated, {} project(s) visible",
+                    client.base_url(),
+                    projects.len()
+                );
+            }
+            Err(e) => {
+                error!(instance = %name, error = %e, "Instance check failed");
+                println!("FAIL  {name} ({}): {e}", client.base_url());
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+pub fn print_tools(json: bool) {
+    let tools = tools::list_tools();
+    if json {
+        println!("{}", serde_json::to_string_pretty(&tools).unwrap_or_default());
+        return;
+    }
+    let width = tools.iter().map(|t| t.name.len()).max().unwrap_or(0);
+    for tool in tools {
+        println!("{:width$}  {}", tool.name, tool.description);
+    }
+}
+
+/// Calls a tool and prints its result. Returns whether the call succeeded.
+pub async fn call(registry: &ClientRegistry, tool: &str, args: Option<&str>, json: bool) -> bool {
+    let args: Option<Value> = match args.map(serde_json::from_str).transpose() {
+        Ok(a) => a,
+        Err(e) => {
+            eprintln!("Invalid --args JSON: {e}");
+            return false;
+        }
+    };
+
+    let result = tools::call_tool(registry, tool, args).await;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&result).unwrap_or_default());
+    } else {
+        print_result(&result);
+    }
+    result.is_error != Some(true)
+}
+
+/// Prints the changes a board spec requires and, unless `dry_run` is set,
+/// applies them. Returns whether the spec was applied without errors.
+pub async fn apply(registry: &ClientRegistry, file: &Path, dry_run: bool, prune: bool, instance: Option<&str>) -> bool {
+    let spec = match std::fs::read_to_string(file) {
+        Ok(text) => BoardSpec::parse(&text),
+        Err(e) => {
+            eprintln!("Cannot read {}: {e}", file.display());
+            return false;
+        }
+    };
+    let result = match (spec, registry.get(instance)) {
+        // The diff printed is the plan that gets applied, not a second planning run
+        (Ok(spec), Ok(client)) => match client.plan_board_spec(&spec, prune).await {
+            Ok(plan) if dry_run || plan.changes.is_empty() => Ok(plan.report(&spec, true)),
+            Ok(plan) => {
+                print!("{}", plan.report(&spec, false).diff());
+                let report = client.apply_changes(&spec, &plan).await;
+                println!("{}", report.summary());
+                return report.error.is_none();
+            }
+            Err(e) => Err(e),
+        },
+        (Err(e), _) | (_, Err(e)) => Err(e),
+    };
+    match result {
+        Ok(report) => {
+            println!("{}", report.to_text());
+            report.error.is_none()
+        }
+        Err(e) => {
+            error!(error = %e, "Failed to apply board spec");
+            eprintln!("Failed to apply board spec: {e}");
+            false
+        }
+    }
+}
+
+/// Backs up a project to `output`, or to stdout. Returns whether the backup was written.
+pub async fn backup(registry: &ClientRegistry, project_id: &str, output: Option<&Path>, instance: Option<&str>) -> bool {
+    let backup = match registry.get(instance) {
+        Ok(client) => client.backup_project(project_id).await,
+        Err(e) => Err(e),
+    };
+    let json = match backup {
+        Ok(backup) => serde_json::to_string_pretty(&backup).unwrap_or_default(),
+        Err(e) => {
+            error!(project_id = %project_id, error = %e, "Failed to back up project");
+            eprintln!("Failed to back up project: {e}");
+            return false;
+        }
+    };
+    match output {
+        Some(path) => match std::fs::write(path, json + "\n") {
+            Ok(()) => {
+                info!(path = %path.display(), "Backup written");
+                true
+            }
+            Err(e) => {
+                eprintln!("Cannot write {}: {e}", path.display());
+                false
+            }
+        },
+        None => {
+            println!("{json}");
+            true
+        }
+    }
+}
+
+/// Restores a project from a backup file and prints what was created.
+/// Returns whether the project was restored.
+pub async fn restore(registry: &ClientRegistry, file: &Path, name: Option<&str>, instance: Option<&str>) -> bool {
+    let backup = match std::fs::read_to_string(file) {
+        Ok(text) => serde_json::from_str::<ProjectBackup>(&text),
+        Err(e) => {
+            eprintln!("Cannot read {}: {e}", file.display());
+            return false;
+        }
+    };
+    let backup = match backup {
+        Ok(b) => b,
+        Err(e) => {
+            eprintln!("Invalid backup {}: {e}", file.display());
+            return false;
+        }
+    };
+    let result = match registry.get(instance) {
+        Ok(client) => client.restore_project(&backup, name).await,
+        Err(e) => Err(e),
+    };
+    match result {
+        Ok(report) => {
+            println!(
+                "Restored project {:?} ({}): {} board(s), {} list(s), {} label(s), {} card(s), {} task(s), {} comment(s)",
+                report.project.name,
+                report.project.id,
+                report.boards.len(),
+                report.lists,
+                report.labels,
+                report.cards,
+                report.tasks,
+                report.comments
+            );
+            for note in &report.skipped {
+                println!("skipped {note}");
+            }
+            true
+        }
+        Err(e) => {
+            error!(error = %e, "Failed to restore project");
+            eprintln!("Failed to restore project: {e}");
+            false
+        }
+    }
+}
+
+fn print_result(result: &ToolCallResult) {
+    for content in &result.content {
+        match content {
+            ToolContent::Text { text } => println!("{text}"),
+            ToolContent::Resource { resource } => print!("{}", resource.text),
+        }
+    }
+}
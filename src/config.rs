@@ -0,0 +1,189 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use tracing::{debug, error, info};
+
+use crate::planka::client::PlankaError;
+use crate::planka::config::ClientConfig;
+
+/// Name of the profile used when neither the flag, `PLANKA_PROFILE` nor
+/// `default_profile` select one
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// TOML configuration file with named Planka profiles
+///
+/// ```toml
+/// default_profile = "staging"
+///
+/// [profiles.staging]
+/// url = "https://planka.staging.example.com"
+/// token = "..."
+/// disable_ssl = true
+///
+/// [profiles.production]
+/// url = "https://planka.example.com"
+/// email = "bot@example.com"
+/// password = "..."
+/// timeout_secs = 30
+/// ```
+///
+/// Setting `instances = ["staging", "production"]` serves several profiles
+/// at once, each addressable through the `instance` tool argument.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFile {
+    pub default_profile: Option<String>,
+    /// Additional profiles served side by side with the selected one
+    #[serde(default)]
+    pub instances: Vec<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, ClientConfig>,
+}
+
+impl ConfigFile {
+    pub fn load(path: &Path) -> Result<Self, PlankaError> {
+        debug!(path = %path.display(), "Loading configuration file");
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            error!(path = %path.display(), error = %e, "Failed to read configuration file");
+            PlankaError::Config(format!("Cannot read {}: {e}", path.display()))
+        })?;
+        let config: Self = toml::from_str(&content).map_err(|e| {
+            error!(path = %path.display(), error = %e, "Invalid configuration file");
+            PlankaError::Config(format!("Invalid config file {}: {e}", path.display()))
+        })?;
+        info!(
+            path = %path.display(),
+            profiles = ?config.profiles.keys().collect::<Vec<_>>(),
+            "Configuration file loaded"
+        );
+        Ok(config)
+    }
+
+    /// Returns the name of the selected profile, or the default one
+    fn profile_name<'a>(&'a self, name: Option<&'a str>) -> &'a str {
+        name.or(self.default_profile.as_deref())
+            .unwrap_or(DEFAULT_PROFILE)
+    }
+
+    /// Returns a profile as written in the file, without environment overrides
+    fn raw_profile(&self, name: &str) -> Result<ClientConfig, PlankaError> {
+        self.profiles.get(name).cloned().ok_or_else(|| {
+            error!(profile = %name, "Profile not found in configuration file");
+            PlankaError::Config(format!("Unknown profile: {name}"))
+        })
+    }
+
+    /// Returns the named profile (or the default one) with environment overrides applied
+    pub fn profile(&self, name: Option<&str>) -> Result<ClientConfig, PlankaError> {
+        let name = self.profile_name(name);
+        let mut profile = self.raw_profile(name)?;
+        info!(profile = %name, "Using configuration profile");
+        profile.apply_env()?;
+        Ok(profile)
+    }
+}
+
+/// Client configurations for every Planka instance the server talks to
+#[derive(Debug, Clone)]
+pub struct InstanceConfigs {
+    /// Name of the instance used when a tool call does not name one
+    pub default: String,
+    /// Configuration per instance, starting with the default one
+    pub instances: Vec<(String, ClientConfig)>,
+}
+
+/// Resolves the client configurations for startup.
+///
+/// Without a config file, the environment alone describes a single instance.
+/// With one, the selected profile is the default instance and receives the
+/// environment overrides; profiles listed in `instances` (or passed in
+/// `extra`) are loaded as written.
+pub fn resolve(
+    path: Option<&Path>,
+    profile: Option<&str>,
+    extra: &[String],
+) -> Result<InstanceConfigs, PlankaError> {
+    let Some(path) = path else {
+        if profile.is_some() || !extra.is_empty() {
+            error!("A profile was selected but no configuration file was given");
+            return Err(PlankaError::Config(
+                "--profile and --instances require --config or PLANKA_CONFIG".into(),
+            ));
+        }
+        debug!("No configuration file given, reading environment variables");
+        return Ok(InstanceConfigs {
+            default: DEFAULT_PROFILE.to_string(),
+            instances: vec![(DEFAULT_PROFILE.to_string(), ClientConfig::from_env()?)],
+        });
+    };
+
+    let file = ConfigFile::load(path)?;
+    let default = file.profile_name(profile).to_string();
+    let mut instances = vec![(default.clone(), file.profile(Some(&default))?)];
+
+    let names = if extra.is_empty() { &file.instances } else { extra };
+    for name in names {
+        if instances.iter().any(|(n, _)| n == name) {
+            continue;
+        }
+        info!(profile = %name, "Adding Planka instance");
+        instances.push((name.clone(), file.raw_profile(name)?));
+    }
+
+    Ok(InstanceConfigs { default, instances })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+        default_profile = "staging"
+
+        [profiles.staging]
+        url = "https://staging.example.com"
+        token = "abc"
+        disable_ssl = true
+
+        [profiles.production]
+        url = "https://example.com"
+        email = "bot@example.com"
+        password = "secret"
+        timeout_secs = 30
+    "#;
+
+    #[test]
+    fn test_parses_profiles() {
+        let config: ConfigFile = toml::from_str(SAMPLE).unwrap();
+        assert_eq!(config.default_profile.as_deref(), Some("staging"));
+        let production = &config.profiles["production"];
+        assert_eq!(production.email.as_deref(), Some("bot@example.com"));
+        assert_eq!(production.timeout_secs, Some(30));
+    }
+
+    #[test]
+    fn test_resolve_loads_default_and_extra_instances() {
+        let path = std::env::temp_dir().join(format!("planka-mcp-test-{}.toml", std::process::id()));
+        std::fs::write(&path, SAMPLE).unwrap();
+        let resolved = resolve(Some(&path), None, &["production".to_string()]);
+        std::fs::remove_file(&path).ok();
+
+        let resolved = resolved.unwrap();
+        assert_eq!(resolved.default, "staging");
+        let names: Vec<&str> = resolved.instances.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["staging", "production"]);
+        assert_eq!(resolved.instances[1].1.timeout_secs, Some(30));
+    }
+
+    #[test]
+    fn test_unknown_profile_is_an_error() {
+        let config: ConfigFile = toml::from_str(SAMPLE).unwrap();
+        assert!(config.profile(Some("qa")).is_err());
+    }
+
+    #[test]
+    fn test_rejects_unknown_keys() {
+        let result: Result<ConfigFile, _> = toml::from_str("[profiles.x]\nurl = \"u\"\ntokn = \"t\"\n");
+        assert!(result.is_err());
+    }
+}
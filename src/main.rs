@@ -1,15 +1,44 @@
+mod cli;
+mod config;
 mod mcp;
 mod planka;
 mod tools;
 
+use clap::Parser;
 use tracing::{error, info};
 use tracing_subscriber::EnvFilter;
 
+use cli::{Cli, Command};
+use mcp::http::HttpServer;
 use mcp::McpServer;
-use planka::PlankaClient;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use planka::client::PlankaError;
+use planka::{ClientRegistry, PlankaClient};
+
+/// Builds one Planka client per configured instance. When `forwarding` is
+/// set, credentials are optional as users supply their own tokens.
+fn build_registry(cli: &Cli, forwarding: bool) -> Result<ClientRegistry, PlankaError> {
+    let configs = config::resolve(cli.config.as_deref(), cli.profile.as_deref(), &cli.instances)?;
+    let mut registry: Option<ClientRegistry> = None;
+    for (name, config) in &configs.instances {
+        let client = if forwarding {
+            PlankaClient::for_forwarding(config)?
+        } else {
+            PlankaClient::from_config(config)?
+        };
+        match registry.as_mut() {
+            Some(registry) => registry.insert(name.clone(), client),
+            None => registry = Some(ClientRegistry::single(configs.default.clone(), client)),
+        }
+    }
+    registry.ok_or_else(|| PlankaError::Config("No Planka instance configured".into()))
+}
 
 #[tokio::main]
 async fn main() {
+    let mut cli = Cli::parse();
+
     // Initialize logging (writes to stderr to keep stdout clean for JSON-RPC)
     // Log level can be configured via RUST_LOG environment variable:
     // - RUST_LOG=error (only errors)
@@ -31,15 +60,26 @@ async fn main() {
         .with_level(true)
         .init();
 
+    let command = cli.command.take().unwrap_or(Command::Serve { http: None });
+    if let Command::Tools { json } = command {
+        cli::print_tools(json);
+        return;
+    }
+
     info!(
         version = %env!("CARGO_PKG_VERSION"),
         "Starting planka-mcp server"
     );
 
-    let client = match PlankaClient::from_env() {
-        Ok(c) => {
-            info!("Planka client initialized successfully");
-            c
+    let forwarding = matches!(command, Command::Serve { http: Some(_) });
+    let registry = match build_registry(&cli, forwarding) {
+        Ok(r) => {
+            info!(
+                instances = r.len(),
+                default = %r.default_name(),
+                "Planka client initialized successfully"
+            );
+            r
         }
         Err(e) => {
             error!(
@@ -50,16 +90,119 @@ async fn main() {
         }
     };
 
-    let server = McpServer::new(client);
-
-    info!("MCP server initialized, starting event loop");
-    if let Err(e) = server.run().await {
-        error!(
-            error = %e,
-            "Server error occurred"
-        );
+    let ok = match command {
+        Command::Serve { http: None } => serve(registry).await,
+        Command::Serve { http: Some(addr) } => serve_http(registry, addr).await,
+        Command::Check => {
+            let ok = cli::check(&registry).await;
+            registry.logout_all().await;
+            ok
+        }
+        Command::Call { tool, args, json } => {
+            let ok = cli::call(&registry, &tool, args.as_deref(), json).await;
+            registry.logout_all().await;
+            ok
+        }
+        Command::Apply { file, dry_run, prune, instance } => {
+            let ok = cli::apply(&registry, &file, dry_run, prune, instance.as_deref()).await;
+            registry.logout_all().await;
+            ok
+        }
+        Command::Backup { project_id, output, instance } => {
+            let ok = cli::backup(&registry, &project_id, output.as_deref(), instance.as_deref()).await;
+            registry.logout_all().await;
+            ok
+        }
+        Command::Restore { file, name, instance } => {
+            let ok = cli::restore(&registry, &file, name.as_deref(), instance.as_deref()).await;
+            registry.logout_all().await;
+            ok
+        }
+        // Handled above, before any client is configured
+        Command::Tools { .. } => true,
+    };
+    if !ok {
         std::process::exit(1);
     }
-    
+}
+
+async fn serve(registry: ClientRegistry) -> bool {
+    let server = McpServer::new(registry);
+
+    info!("MCP server initialized, starting event loop");
+    let ok = tokio::select! {
+        result = server.run() => match result {
+            Ok(()) => true,
+            Err(e) => {
+                error!(
+                    error = %e,
+                    "Server error occurred"
+                );
+                false
+            }
+        },
+        signal = shutdown_signal() => {
+            info!(signal = %signal, "Received shutdown signal");
+            true
+        }
+    };
+
+    server.shutdown().await;
     info!("Server shutdown complete");
+    ok
+}
+
+async fn serve_http(registry: ClientRegistry, addr: SocketAddr) -> bool {
+    let server = Arc::new(HttpServer::new(registry));
+
+    let ok = tokio::select! {
+        result = server.clone().run(addr) => match result {
+            Ok(()) => true,
+            Err(e) => {
+                error!(
+                    error = %e,
+                    "HTTP server error occurred"
+                );
+                false
+            }
+        },
+        signal = shutdown_signal() => {
+            info!(signal = %signal, "Received shutdown signal");
+            true
+        }
+    };
+
+    server.shutdown().await;
+    info!("Server shutdown complete");
+    ok
+}
+
+/// Waits for SIGINT or, on Unix, SIGTERM and returns the signal name
+async fn shutdown_signal() -> &'static str {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => tokio::select! {
+                _ = tokio::signal::ctrl_c() => "SIGINT",
+                _ = sigterm.recv() => "SIGTERM",
+            },
+            Err(e) => {
+                error!(error = %e, "Failed to install SIGTERM handler");
+                ctrl_c().await
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        ctrl_c().await
+    }
+}
+
+async fn ctrl_c() -> &'static str {
+    if let Err(e) = tokio::signal::ctrl_c().await {
+        error!(error = %e, "Failed to install SIGINT handler");
+        std::future::pending::<()>().await;
+    }
+    "SIGINT"
 }
@@ -0,0 +1,398 @@
+use http_body_util::{BodyExt, Full, Limited};
+use hyper::body::{Bytes, Incoming};
+use hyper::header::{self, HeaderValue};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::TcpListener;
+use tokio::sync::Mutex;
+use tracing::{debug, error, info, trace, warn};
+
+use crate::planka::client::PlankaError;
+use crate::planka::ClientRegistry;
+
+use super::server::McpServer;
+
+/// Path of the MCP endpoint
+const MCP_PATH: &str = "/mcp";
+const SESSION_HEADER: &str = "mcp-session-id";
+/// Largest accepted request body
+const MAX_BODY_BYTES: usize = 4 * 1024 * 1024;
+/// Sessions without requests for this long are dropped
+const SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(60 * 60);
+/// Pause after a failed accept, giving the cause (e.g. exhausted file descriptors) time to clear
+const ACCEPT_RETRY_DELAY: Duration = Duration::from_millis(100);
+/// Most sessions open at once; further `initialize` requests are refused
+const MAX_SESSIONS: usize = 256;
+
+/// An MCP session bound to the Planka token of the user who opened it
+struct Session {
+    token: String,
+    server: Arc<McpServer>,
+    last_seen: Instant,
+}
+
+/// Streamable HTTP transport where each session acts as its own Planka user.
+///
+/// Clients send their Planka token as `Authorization: Bearer <token>`. The
+/// `initialize` request checks the token against Planka and opens a session
+/// with a dedicated client for it, so Planka attributes every action to the
+/// real user and applies their permissions. Later requests must carry the
+/// `Mcp-Session-Id` header returned by `initialize` together with the same
+/// token. Idle sessions are dropped whenever a request comes in.
+pub struct HttpServer {
+    registry: ClientRegistry,
+    sessions: Mutex<HashMap<String, Session>>,
+}
+
+type HttpResponse = Response<Full<Bytes>>;
+
+impl HttpServer {
+    pub fn new(registry: ClientRegistry) -> Self {
+        if registry.len() > 1 {
+            warn!(
+                default = %registry.default_name(),
+                "HTTP mode forwards user tokens to the default instance only"
+            );
+        }
+        Self {
+            registry,
+            sessions: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub async fn run(self: Arc<Self>, addr: SocketAddr) -> Result<(), Box<dyn std::error::Error>> {
+        let listener = TcpListener::bind(addr).await?;
+        info!(addr = %addr, path = MCP_PATH, "MCP HTTP server listening");
+
+        loop {
+            let (stream, peer) = match listener.accept().await {
+                Ok(accepted) => accepted,
+                Err(e) => {
+                    // Errors such as EMFILE or ECONNABORTED pass; stopping would end every session
+                    warn!(error = %e, "Failed to accept connection, retrying");
+                    tokio::time::sleep(ACCEPT_RETRY_DELAY).await;
+                    continue;
+                }
+            };
+            trace!(peer = %peer, "Accepted connection");
+            let server = self.clone();
+            tokio::spawn(async move {
+                let service = service_fn(move |req| {
+                    let server = server.clone();
+                    async move { Ok::<_, hyper::Error>(server.handle(req).await) }
+                });
+                if let Err(e) = http1::Builder::new()
+                    .serve_connection(TokioIo::new(stream), service)
+                    .await
+                {
+                    debug!(peer = %peer, error = %e, "Connection closed with error");
+                }
+            });
+        }
+    }
+
+    /// Closes all sessions
+    pub async fn shutdown(&self) {
+        let sessions: Vec<Session> = self.sessions.lock().await.drain().map(|(_, s)| s).collect();
+        info!(sessions = sessions.len(), "Closing MCP HTTP sessions");
+        for session in sessions {
+            session.server.shutdown().await;
+        }
+        self.registry.logout_all().await;
+    }
+
+    async fn handle(&self, req: Request<Incoming>) -> HttpResponse {
+        debug!(method = %req.method(), path = %req.uri().path(), "Received HTTP request");
+        if req.uri().path() != MCP_PATH {
+            return status(StatusCode::NOT_FOUND, "Not found");
+        }
+
+        let Some(token) = bearer_token(&req) else {
+            warn!("Rejected request without a Planka token");
+            let mut resp = status(StatusCode::UNAUTHORIZED, "Missing Authorization: Bearer <planka token>");
+            resp.headers_mut()
+                .insert(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+            return resp;
+        };
+        let session_id = req
+            .headers()
+            .get(SESSION_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+
+        match *req.method() {
+            Method::POST => self.handle_post(req, token, session_id).await,
+            Method::DELETE => self.handle_delete(&token, session_id).await,
+            // Server-initiated messages are not used, so there is no SSE stream
+            _ => {
+                let mut resp = status(StatusCode::METHOD_NOT_ALLOWED, "Method not allowed");
+                resp.headers_mut()
+                    .insert(header::ALLOW, HeaderValue::from_static("POST, DELETE"));
+                resp
+            }
+        }
+    }
+
+    async fn handle_post(
+        &self,
+        req: Request<Incoming>,
+        token: String,
+        session_id: Option<String>,
+    ) -> HttpResponse {
+        let body = match Limited::new(req.into_body(), MAX_BODY_BYTES).collect().await {
+            Ok(body) => body.to_bytes(),
+            Err(e) => {
+                warn!(error = %e, "Failed to read request body");
+                return status(StatusCode::PAYLOAD_TOO_LARGE, "Request body too large or unreadable");
+            }
+        };
+        let Ok(message) = std::str::from_utf8(&body) else {
+            return status(StatusCode::BAD_REQUEST, "Request body is not UTF-8");
+        };
+
+        let (session_id, server) = match session_id {
+            Some(id) => match self.session(&id, &token).await {
+                Ok(server) => (id, server),
+                Err(resp) => return resp,
+            },
+            None if is_initialize(message) => match self.open_session(token).await {
+                Ok(session) => session,
+                Err(resp) => return resp,
+            },
+            None => {
+                return status(StatusCode::BAD_REQUEST, "Missing Mcp-Session-Id header");
+            }
+        };
+
+        let mut resp = match server.handle_message(message).await {
+            Some(response) => match serde_json::to_vec(&response) {
+                Ok(json) => {
+                    let mut resp = Response::new(Full::new(Bytes::from(json)));
+                    resp.headers_mut()
+                        .insert(header::CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                    resp
+                }
+                Err(e) => {
+                    error!(error = %e, "Failed to serialize JSON-RPC response");
+                    return status(StatusCode::INTERNAL_SERVER_ERROR, "Failed to serialize response");
+                }
+            },
+            None => status(StatusCode::ACCEPTED, ""),
+        };
+        if let Ok(value) = HeaderValue::from_str(&session_id) {
+            resp.headers_mut().insert(SESSION_HEADER, value);
+        }
+        resp
+    }
+
+    async fn handle_delete(&self, token: &str, session_id: Option<String>) -> HttpResponse {
+        let Some(id) = session_id else {
+            return status(StatusCode::BAD_REQUEST, "Missing Mcp-Session-Id header");
+        };
+        let mut sessions = self.sessions.lock().await;
+        drop_idle(&mut sessions, Instant::now());
+        match sessions.get(&id) {
+            Some(session) if tokens_match(&session.token, token) => {
+                let session = sessions.remove(&id).expect("session exists");
+                drop(sessions);
+                session.server.shutdown().await;
+                info!(session = %id, "MCP session closed by client");
+                status(StatusCode::NO_CONTENT, "")
+            }
+            Some(_) => status(StatusCode::FORBIDDEN, "Session belongs to another token"),
+            None => status(StatusCode::NOT_FOUND, "Unknown session"),
+        }
+    }
+
+    async fn open_session(&self, token: String) -> Result<(String, Arc<McpServer>), HttpResponse> {
+        // Checked before and after validating, which leaves the lock free meanwhile
+        if is_full(&mut *self.sessions.lock().await, Instant::now()) {
+            return Err(status(StatusCode::SERVICE_UNAVAILABLE, "Too many open sessions"));
+        }
+
+        let registry = self.registry.for_token(&token);
+        let client = registry.get(None).map_err(|e| {
+            error!(error = %e, "No default instance for the session");
+            status(StatusCode::INTERNAL_SERVER_ERROR, "No Planka instance configured")
+        })?;
+        match client.current_user().await {
+            Ok(user) => debug!(user_id = %user.id, "Validated session token"),
+            Err(PlankaError::Status(401 | 403, _)) => {
+                warn!("Rejected session for a token Planka does not accept");
+                return Err(status(StatusCode::UNAUTHORIZED, "Planka rejected the token"));
+            }
+            Err(e) => {
+                error!(error = %e, "Failed to validate session token");
+                return Err(status(StatusCode::BAD_GATEWAY, "Failed to reach Planka to validate the token"));
+            }
+        }
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let server = Arc::new(McpServer::new(registry));
+        let mut sessions = self.sessions.lock().await;
+        if is_full(&mut sessions, Instant::now()) {
+            return Err(status(StatusCode::SERVICE_UNAVAILABLE, "Too many open sessions"));
+        }
+        sessions.insert(
+            id.clone(),
+            Session {
+                token,
+                server: server.clone(),
+                last_seen: Instant::now(),
+            },
+        );
+        info!(session = %id, active = sessions.len(), "MCP session opened");
+        Ok((id, server))
+    }
+
+    async fn session(&self, id: &str, token: &str) -> Result<Arc<McpServer>, HttpResponse> {
+        let mut sessions = self.sessions.lock().await;
+        drop_idle(&mut sessions, Instant::now());
+        match sessions.get_mut(id) {
+            Some(session) if tokens_match(&session.token, token) => {
+                session.last_seen = Instant::now();
+                Ok(session.server.clone())
+            }
+            Some(_) => {
+                warn!(session = %id, "Rejected request with a token not matching the session");
+                Err(status(StatusCode::FORBIDDEN, "Session belongs to another token"))
+            }
+            None => {
+                debug!(session = %id, "Request for unknown session");
+                Err(status(StatusCode::NOT_FOUND, "Unknown session"))
+            }
+        }
+    }
+}
+
+/// Drops sessions that saw no request for `SESSION_IDLE_TIMEOUT` before `now`
+fn drop_idle(sessions: &mut HashMap<String, Session>, now: Instant) {
+    sessions.retain(|id, s| {
+        let keep = now.saturating_duration_since(s.last_seen) < SESSION_IDLE_TIMEOUT;
+        if !keep {
+            debug!(session = %id, "Dropping idle MCP session");
+        }
+        keep
+    });
+}
+
+/// Drops idle sessions and tells whether `MAX_SESSIONS` are still open
+fn is_full(sessions: &mut HashMap<String, Session>, now: Instant) -> bool {
+    drop_idle(sessions, now);
+    let full = sessions.len() >= MAX_SESSIONS;
+    if full {
+        warn!(active = sessions.len(), "Rejected new MCP session, session limit reached");
+    }
+    full
+}
+
+/// Compares two tokens in time independent of where they differ
+fn tokens_match(a: &str, b: &str) -> bool {
+    a.len() == b.len() && a.bytes().zip(b.bytes()).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+fn bearer_token<B>(req: &Request<B>) -> Option<String> {
+    let value = req.headers().get(header::AUTHORIZATION)?.to_str().ok()?;
+    let (scheme, token) = value.split_once(' ')?;
+    let token = token.trim();
+    (scheme.eq_ignore_ascii_case("bearer") && !token.is_empty()).then(|| token.to_string())
+}
+
+fn is_initialize(message: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(message)
+        .ok()
+        .is_some_and(|v| v["method"] == "initialize")
+}
+
+fn status(code: StatusCode, text: &'static str) -> HttpResponse {
+    let mut resp = Response::new(Full::new(Bytes::from_static(text.as_bytes())));
+    *resp.status_mut() = code;
+    resp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::planka::config::ClientConfig;
+    use crate::planka::PlankaClient;
+
+    fn server() -> Arc<McpServer> {
+        let config = ClientConfig {
+            url: Some("http://127.0.0.1:1".to_string()),
+            token: Some("service".to_string()),
+            ..Default::default()
+        };
+        let client = PlankaClient::from_config(&config).unwrap();
+        Arc::new(McpServer::new(ClientRegistry::single("default", client)))
+    }
+
+    fn sessions(count: usize, server: &Arc<McpServer>) -> HashMap<String, Session> {
+        (0..count)
+            .map(|i| {
+                let session = Session {
+                    token: format!("token-{i}"),
+                    server: server.clone(),
+                    last_seen: Instant::now(),
+                };
+                (i.to_string(), session)
+            })
+            .collect()
+    }
+
+    fn request(authorization: Option<&str>) -> Request<()> {
+        let mut builder = Request::builder().uri(MCP_PATH);
+        if let Some(value) = authorization {
+            builder = builder.header(header::AUTHORIZATION, value);
+        }
+        builder.body(()).unwrap()
+    }
+
+    #[test]
+    fn test_tokens_match() {
+        assert!(tokens_match("secret", "secret"));
+        assert!(!tokens_match("secret", "secreT"));
+        assert!(!tokens_match("secret", "secret2"));
+        assert!(!tokens_match("", "secret"));
+    }
+
+    #[test]
+    fn test_bearer_token() {
+        assert_eq!(bearer_token(&request(Some("Bearer abc"))).as_deref(), Some("abc"));
+        assert_eq!(bearer_token(&request(Some("bearer  abc "))).as_deref(), Some("abc"));
+        assert_eq!(bearer_token(&request(Some("Bearer "))), None);
+        assert_eq!(bearer_token(&request(Some("Bearerabc"))), None);
+        assert_eq!(bearer_token(&request(Some("Basic abc"))), None);
+        assert_eq!(bearer_token(&request(None)), None);
+    }
+
+    #[test]
+    fn test_is_initialize() {
+        assert!(is_initialize(r#"{"jsonrpc": "2.0", "id": 1, "method": "initialize"}"#));
+        assert!(!is_initialize(r#"{"jsonrpc": "2.0", "id": 1, "method": "tools/list"}"#));
+        assert!(!is_initialize("not json"));
+    }
+
+    #[test]
+    fn test_drop_idle_and_session_limit() {
+        let server = server();
+        let mut open = sessions(2, &server);
+        drop_idle(&mut open, Instant::now());
+        assert_eq!(open.len(), 2);
+        drop_idle(&mut open, Instant::now() + SESSION_IDLE_TIMEOUT);
+        assert!(open.is_empty());
+
+        let mut open = sessions(MAX_SESSIONS, &server);
+        assert!(is_full(&mut open, Instant::now()));
+        open.remove("0");
+        assert!(!is_full(&mut open, Instant::now()));
+        let mut open = sessions(MAX_SESSIONS, &server);
+        assert!(!is_full(&mut open, Instant::now() + SESSION_IDLE_TIMEOUT));
+        assert!(open.is_empty());
+    }
+}
@@ -2,19 +2,19 @@ use serde_json::{json, Value};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tracing::{debug, error, info, trace, warn};
 
-use crate::planka::PlankaClient;
+use crate::planka::ClientRegistry;
 use crate::tools;
 
 use super::types::*;
 
 pub struct McpServer {
-    client: PlankaClient,
+    registry: ClientRegistry,
 }
 
 impl McpServer {
-    pub fn new(client: PlankaClient) -> Self {
+    pub fn new(registry: ClientRegistry) -> Self {
         info!("MCP server instance created");
-        Self { client }
+        Self { registry }
     }
 
     pub async fn run(&self) -> Result<(), Box<dyn std::error::Error>> {
@@ -61,7 +61,13 @@ impl McpServer {
         Ok(())
     }
 
-    async fn handle_message(&self, msg: &str) -> Option<JsonRpcResponse> {
+    /// Releases server-side resources, such as access tokens created at login
+    pub async fn shutdown(&self) {
+        info!("Shutting down MCP server");
+        self.registry.logout_all().await;
+    }
+
+    pub async fn handle_message(&self, msg: &str) -> Option<JsonRpcResponse> {
         let request: JsonRpcRequest = match serde_json::from_str::<JsonRpcRequest>(msg) {
             Ok(req) => {
                 debug!(
@@ -203,7 +209,7 @@ impl McpServer {
         info!(tool = %params.name, "Calling tool");
         trace!(tool_args = ?params.arguments, "Tool arguments");
 
-        let result = tools::call_tool(&self.client, &params.name, params.arguments).await;
+        let result = tools::call_tool(&self.registry, &params.name, params.arguments).await;
 
         trace!(tool_result = ?result, "Tool execution result");
 
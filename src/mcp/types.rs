@@ -159,6 +159,16 @@ pub struct ToolCallResult {
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum ToolContent {
     Text { text: String },
+    /// A document embedded in the result, which clients may save or display
+    Resource { resource: EmbeddedResource },
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbeddedResource {
+    pub uri: String,
+    pub mime_type: String,
+    pub text: String,
 }
 
 impl ToolCallResult {
@@ -169,6 +179,19 @@ impl ToolCallResult {
         }
     }
 
+    pub fn resource(uri: impl Into<String>, mime_type: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            content: vec![ToolContent::Resource {
+                resource: EmbeddedResource {
+                    uri: uri.into(),
+                    mime_type: mime_type.into(),
+                    text: text.into(),
+                },
+            }],
+            is_error: None,
+        }
+    }
+
     pub fn error(text: impl Into<String>) -> Self {
         Self {
             content: vec![ToolContent::Text { text: text.into() }],
@@ -241,4 +264,18 @@ mod tests {
         assert_eq!(json["name"], "test_tool");
         assert!(!json.as_object().unwrap().contains_key("annotations"));
     }
+
+    #[test]
+    fn test_resource_result_serializes_as_embedded_resource() {
+        let result = ToolCallResult::resource("planka://boards/1/export.md", "text/markdown", "# Board");
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(
+            json["content"][0],
+            json!({
+                "type": "resource",
+                "resource": {"uri": "planka://boards/1/export.md", "mimeType": "text/markdown", "text": "# Board"}
+            })
+        );
+    }
 }
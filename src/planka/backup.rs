@@ -0,0 +1,293 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use tracing::{debug, info, warn};
+
+use super::client::{PlankaClient, PlankaError};
+use super::position::{Placement, POSITION_GAP};
+use super::types::{BoardResponse, Comment, CreateCardOptions, Project};
+
+/// Version of the backup document layout
+pub const BACKUP_FORMAT: u32 = 1;
+
+/// A project with everything on its boards, as returned by Planka
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectBackup {
+    pub format: u32,
+    /// Planka server the backup was taken from
+    #[serde(default)]
+    pub source: Option<String>,
+    /// Project item of `GET /api/projects/{id}`
+    pub project: Value,
+    pub boards: Vec<BoardBackup>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardBackup {
+    /// `GET /api/boards/{id}` with lists, cards, labels, task lists, tasks and attachment metadata
+    pub board: Value,
+    /// Comments of every card on the board
+    #[serde(default)]
+    pub comments: Vec<Value>,
+}
+
+/// What a restore created, with the ids of the new boards by their old ids
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreReport {
+    pub project: Project,
+    pub boards: BTreeMap<String, String>,
+    pub lists: usize,
+    pub labels: usize,
+    pub cards: usize,
+    pub task_lists: usize,
+    pub tasks: usize,
+    pub comments: usize,
+    /// Parts of the backup that could not be restored
+    pub skipped: Vec<String>,
+}
+
+impl PlankaClient {
+    /// Reads a project with its boards and the comments of every card
+    pub async fn backup_project(&self, project_id: &str) -> Result<ProjectBackup, PlankaError> {
+        info!(project_id = %project_id, "Backing up project");
+        self.ensure_project_in_scope(project_id).await?;
+        let project = self.get_value(&format!("/api/projects/{project_id}")).await?;
+        let boards = self.list_boards(project_id, true).await?;
+
+        let mut backup = ProjectBackup {
+            format: BACKUP_FORMAT,
+            source: Some(self.base_url().to_string()),
+            project: project["item"].clone(),
+            boards: Vec::with_capacity(boards.len()),
+        };
+        for board in boards {
+            let data = self.export_board(&board.id).await?;
+            let card_ids: Vec<String> = data["included"]["cards"]
+                .as_array()
+                .map(|cards| cards.iter().filter_map(|c| c["id"].as_str().map(str::to_string)).collect())
+                .unwrap_or_default();
+            let mut comments = Vec::new();
+            for card_id in card_ids {
+                comments.extend(self.card_comments(&card_id).await?);
+            }
+            debug!(board_id = %board.id, comments = comments.len(), "Backed up board");
+            backup.boards.push(BoardBackup { board: data, comments });
+        }
+
+        info!(project_id = %project_id, boards = backup.boards.len(), "Project backed up successfully");
+        Ok(backup)
+    }
+
+    /// Reads every comment of a card, following `beforeId` from page to page
+    async fn card_comments(&self, card_id: &str) -> Result<Vec<Value>, PlankaError> {
+        let mut comments: Vec<Value> = Vec::new();
+        loop {
+            let path = match comments.last().and_then(|c| c["id"].as_str()) {
+                Some(before_id) => format!("/api/cards/{card_id}/comments?beforeId={before_id}"),
+                None => format!("/api/cards/{card_id}/comments"),
+            };
+            let response = self.get_value(&path).await?;
+            let page = match response["items"].as_array() {
+                Some(items) if !items.is_empty() => items,
+                _ => break,
+            };
+            // A page ending on the same comment as the last one means paging is not supported
+            let last_id = page.last().and_then(|c| c["id"].as_str());
+            if last_id.is_none() || last_id == comments.last().and_then(|c| c["id"].as_str()) {
+                break;
+            }
+            comments.extend(page.iter().cloned());
+        }
+        Ok(comments)
+    }
+
+    /// Recreates a backed up project under new ids, optionally under another
+    /// name. Users are not remapped, so card and board members are left out.
+    /// If any step fails, the new project is deleted again.
+    pub async fn restore_project(&self, backup: &ProjectBackup, name: Option<&str>) -> Result<RestoreReport, PlankaError> {
+        if backup.format != BACKUP_FORMAT {
+            return Err(PlankaError::Invalid(format!(
+                "unsupported backup format {} (expected {BACKUP_FORMAT})",
+                backup.format
+            )));
+        }
+        let source: Project = serde_json::from_value(backup.project.clone())
+            .map_err(|e| PlankaError::Invalid(format!("invalid project in backup: {e}")))?;
+        let boards = backup
+            .boards
+            .iter()
+            .map(|b| {
+                let data: BoardResponse = serde_json::from_value(b.board.clone())
+                    .map_err(|e| PlankaError::Invalid(format!("invalid board in backup: {e}")))?;
+                let comments: Vec<Comment> = serde_json::from_value(Value::Array(b.comments.clone()))
+                    .map_err(|e| PlankaError::Invalid(format!("invalid comments in backup: {e}")))?;
+                let attachments = b.board["included"]["attachments"].as_array().map_or(0, Vec::len);
+                Ok((data, comments, attachments))
+            })
+            .collect::<Result<Vec<_>, PlankaError>>()?;
+
+        for (data, _, _) in &boards {
+            if !self.scope.allows_board_name("", &data.item.name) {
+                warn!(name = %data.item.name, "Rejected board restore outside configured scope");
+                return Err(PlankaError::OutOfScope(format!(
+                    "board name {:?} is outside the configured scope",
+                    data.item.name
+                )));
+            }
+        }
+
+        let name = name.unwrap_or(&source.name);
+        info!(source_project_id = %source.id, name = %name, boards = boards.len(), "Restoring project");
+        let project_type = if source.owner_project_manager_id.is_some() { "private" } else { "shared" };
+        let project = self.create_project(name, project_type, source.description.clone()).await?;
+
+        let mut report = RestoreReport {
+            project,
+            boards: BTreeMap::new(),
+            lists: 0,
+            labels: 0,
+            cards: 0,
+            task_lists: 0,
+            tasks: 0,
+            comments: 0,
+            skipped: Vec::new(),
+        };
+        for (i, (data, comments, attachments)) in boards.iter().enumerate() {
+            if let Err(e) = self.restore_board(data, comments, i, &mut report).await {
+                warn!(project_id = %report.project.id, error = %e, "Restoring project failed, deleting the partial project");
+                for board_id in report.boards.values() {
+                    if let Err(cleanup) = self.delete_board_in_scope(board_id).await {
+                        warn!(board_id = %board_id, error = %cleanup, "Failed to delete partially restored board");
+                    }
+                }
+                if let Err(cleanup) = self.delete_project(&report.project.id, &report.project.name).await {
+                    warn!(project_id = %report.project.id, error = %cleanup, "Failed to delete partially restored project");
+                }
+                return Err(e);
+            }
+            if *attachments > 0 {
+                report.skipped.push(format!(
+                    "board {:?}: {attachments} attachment(s), as only their metadata is backed up",
+                    data.item.name
+                ));
+            }
+        }
+
+        info!(
+            project_id = %report.project.id,
+            boards = report.boards.len(),
+            cards = report.cards,
+            skipped = report.skipped.len(),
+            "Project restored successfully"
+        );
+        Ok(report)
+    }
+
+    async fn restore_board(
+        &self,
+        data: &BoardResponse,
+        comments: &[Comment],
+        index: usize,
+        report: &mut RestoreReport,
+    ) -> Result<(), PlankaError> {
+        let src = &data.included;
+        let position = data.item.position.unwrap_or((index + 1) as f64 * POSITION_GAP);
+        let board = self.create_board_at(&report.project.id, &data.item.name, position).await?;
+        report.boards.insert(data.item.id.clone(), board.id.clone());
+
+        // Archive and trash lists have no position and exist on every board already
+        let mut lists = HashMap::new();
+        for list in src.lists.iter().filter(|l| l.position.is_some()) {
+            let created = self
+                .create_list_at(&board.id, &list.name, list.position.unwrap_or(POSITION_GAP))
+                .await?;
+            lists.insert(list.id.as_str(), created.id);
+            report.lists += 1;
+        }
+
+        let mut labels = HashMap::new();
+        for (i, label) in src.labels.iter().enumerate() {
+            let position = label.position.unwrap_or((i + 1) as f64 * POSITION_GAP);
+            let created = self
+                .create_label(&board.id, label.name.as_deref(), &label.color, position)
+                .await?;
+            labels.insert(label.id.as_str(), created.id);
+            report.labels += 1;
+        }
+
+        let mut skipped_cards = 0;
+        for card in &src.cards {
+            let Some(list_id) = lists.get(card.list_id.as_str()) else {
+                skipped_cards += 1;
+                continue;
+            };
+            let created = self
+                .create_card(CreateCardOptions {
+                    list_id: list_id.clone(),
+                    placement: Placement::At(card.position.unwrap_or(POSITION_GAP)),
+                    card_type: card.card_type.clone(),
+                    name: card.name.clone(),
+                    description: card.description.clone(),
+                    due_date: card.due_date.clone(),
+                    is_due_completed: card.is_due_completed,
+                    stopwatch: card.stopwatch.clone(),
+                })
+                .await?;
+            report.cards += 1;
+
+            for card_label in src.card_labels.iter().filter(|cl| cl.card_id == card.id) {
+                if let Some(label_id) = labels.get(card_label.label_id.as_str()) {
+                    self.add_card_label(&created.id, label_id).await?;
+                }
+            }
+            let (task_lists, tasks) = self.copy_task_lists(src, &card.id, &created.id).await?;
+            report.task_lists += task_lists;
+            report.tasks += tasks;
+
+            let mut card_comments: Vec<&Comment> = comments.iter().filter(|c| c.card_id == card.id).collect();
+            card_comments.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+            for comment in card_comments {
+                self.create_comment(&created.id, &comment.text).await?;
+                report.comments += 1;
+            }
+        }
+
+        let name = &data.item.name;
+        if skipped_cards > 0 {
+            report
+                .skipped
+                .push(format!("board {name:?}: {skipped_cards} archived or trashed card(s)"));
+        }
+        let members = src.card_memberships.len() + src.board_memberships.len();
+        if members > 0 {
+            report
+                .skipped
+                .push(format!("board {name:?}: {members} board and card membership(s), as users are not remapped"));
+        }
+        debug!(board_id = %board.id, "Board restored");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_backup_document_defaults_optional_parts() {
+        let backup: ProjectBackup = serde_json::from_value(json!({
+            "format": 1,
+            "project": {"id": "10", "name": "Engineering"},
+            "boards": [{"board": {"item": {"id": "100", "name": "Sprint"}, "included": {}}}]
+        }))
+        .unwrap();
+        assert_eq!(backup.source, None);
+        assert!(backup.boards[0].comments.is_empty());
+        let board: BoardResponse = serde_json::from_value(backup.boards[0].board.clone()).unwrap();
+        assert_eq!(board.item.name, "Sprint");
+    }
+}
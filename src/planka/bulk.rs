@@ -0,0 +1,489 @@
+use futures_util::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use tracing::{debug, info, warn};
+
+use super::client::{PlankaClient, PlankaError};
+use super::position::{position_for, Placement};
+use super::types::{BoardResponse, UpdateCardOptions};
+
+/// Requests a bulk operation runs at once unless told otherwise
+pub const DEFAULT_BULK_CONCURRENCY: usize = 4;
+/// Upper bound on the requests a bulk operation runs at once
+pub const MAX_BULK_CONCURRENCY: usize = 16;
+
+/// Cards on one board matching every given condition
+#[derive(Debug, Clone, Deserialize)]
+pub struct CardFilter {
+    pub board_id: String,
+    /// List id or name
+    pub list: Option<String>,
+    /// Label id or name
+    pub label: Option<String>,
+    /// Case-insensitive substring of the card name
+    pub name_contains: Option<String>,
+}
+
+impl CardFilter {
+    /// Whether any condition besides the board is set
+    pub fn is_narrowed(&self) -> bool {
+        self.list.is_some() || self.label.is_some() || self.name_contains.as_deref().is_some_and(|n| !n.is_empty())
+    }
+
+    /// Ids of the matching cards in board order (by list, then by position)
+    pub fn matching_cards(&self, data: &BoardResponse) -> Result<Vec<String>, PlankaError> {
+        let included = &data.included;
+        let list_id = match &self.list {
+            Some(key) => Some(resolve(
+                "list",
+                key,
+                included.lists.iter().map(|l| (l.id.as_str(), Some(l.name.as_str()))),
+            )?),
+            None => None,
+        };
+        let label_id = match &self.label {
+            Some(key) => Some(resolve(
+                "label",
+                key,
+                included.labels.iter().map(|l| (l.id.as_str(), l.name.as_deref())),
+            )?),
+            None => None,
+        };
+        let needle = self.name_contains.as_deref().map(str::to_lowercase);
+
+        let list_position = |id: &str| {
+            included
+                .lists
+                .iter()
+                .find(|l| l.id == id)
+                .and_then(|l| l.position)
+                .unwrap_or(f64::MAX)
+        };
+        let mut cards: Vec<_> = included
+            .cards
+            .iter()
+            .filter(|c| list_id.is_none_or(|id| c.list_id == id))
+            .filter(|c| {
+                label_id.is_none_or(|id| {
+                    included
+                        .card_labels
+                        .iter()
+                        .any(|cl| cl.card_id == c.id && cl.label_id == id)
+                })
+            })
+            .filter(|c| needle.as_deref().is_none_or(|n| c.name.to_lowercase().contains(n)))
+            .collect();
+        cards.sort_by(|a, b| {
+            list_position(&a.list_id)
+                .total_cmp(&list_position(&b.list_id))
+                .then(a.position.unwrap_or(f64::MAX).total_cmp(&b.position.unwrap_or(f64::MAX)))
+        });
+        Ok(cards.into_iter().map(|c| c.id.clone()).collect())
+    }
+}
+
+/// Finds the item whose id, or else whose name, is `key`
+fn resolve<'a>(
+    kind: &str,
+    key: &str,
+    items: impl Iterator<Item = (&'a str, Option<&'a str>)> + Clone,
+) -> Result<&'a str, PlankaError> {
+    if let Some((id, _)) = items.clone().find(|(id, _)| *id == key) {
+        return Ok(id);
+    }
+    let mut named = items.filter(|(_, name)| *name == Some(key));
+    match (named.next(), named.next()) {
+        (Some((id, _)), None) => Ok(id),
+        (Some(_), Some(_)) => Err(PlankaError::Invalid(format!("{kind} name {key:?} is ambiguous, use its id"))),
+        (None, _) => Err(PlankaError::Invalid(format!("no {kind} {key:?} on the board"))),
+    }
+}
+
+/// Cards a bulk operation applies to
+#[derive(Debug, Clone)]
+pub enum CardSelection {
+    Ids(Vec<String>),
+    Filter(CardFilter),
+}
+
+/// Outcome of a bulk operation on one card
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkCardResult {
+    pub card_id: String,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Per-card outcomes of a bulk operation, in selection order
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkReport {
+    pub matched: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub results: Vec<BulkCardResult>,
+}
+
+/// Positions for cards added to a list, and the siblings that must be moved first
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spread {
+    pub positions: Vec<f64>,
+    pub renumber: Vec<(String, f64)>,
+}
+
+/// Positions for `count` cards added to a list whose cards are `siblings`,
+/// keeping the added cards in the given order. Cards in `moving` are
+/// ignored, as they leave their current place. Each card is placed with
+/// [`position_for`], the first by `placement` and the rest after the
+/// previous one, so crowded siblings are renumbered as for a single card.
+pub fn spread_positions(
+    siblings: &[(&str, Option<f64>)],
+    moving: &[String],
+    count: usize,
+    placement: &Placement,
+) -> Result<Spread, PlankaError> {
+    // Ids that cannot clash with Planka's numeric ones stand in for the added cards
+    let added: Vec<String> = (0..count).map(|i| format!("added:{i}")).collect();
+    let mut current: Vec<(String, Option<f64>)> = siblings
+        .iter()
+        .filter(|(id, _)| !moving.iter().any(|m| m == id))
+        .map(|(id, position)| (id.to_string(), *position))
+        .collect();
+    let mut renumbered = HashSet::new();
+
+    for (i, id) in added.iter().enumerate() {
+        let placement = match i {
+            0 => placement.clone(),
+            _ => Placement::After(added[i - 1].clone()),
+        };
+        let view: Vec<(&str, Option<f64>)> = current.iter().map(|(id, p)| (id.as_str(), *p)).collect();
+        let slot = position_for(&view, &placement, None)?;
+        for (sibling, position) in slot.renumber {
+            if let Some(entry) = current.iter_mut().find(|(id, _)| *id == sibling) {
+                entry.1 = Some(position);
+            }
+            renumbered.insert(sibling);
+        }
+        current.push((id.clone(), Some(slot.position)));
+    }
+
+    let position_of = |id: &str| current.iter().find(|(c, _)| c == id).and_then(|(_, p)| *p);
+    let positions = added.iter().filter_map(|id| position_of(id)).collect();
+    let renumber = current
+        .iter()
+        .filter(|(id, _)| renumbered.contains(id) && !added.contains(id))
+        .filter_map(|(id, position)| position.map(|p| (id.clone(), p)))
+        .collect();
+    Ok(Spread { positions, renumber })
+}
+
+/// Refuses a deletion that could empty a board by accident: a filter with
+/// nothing but the board needs `expected_count`, and a given
+/// `expected_count` must equal the number of selected cards
+pub fn check_delete_guard(
+    selection: &CardSelection,
+    selected: usize,
+    expected_count: Option<usize>,
+) -> Result<(), PlankaError> {
+    if let (CardSelection::Filter(filter), None) = (selection, expected_count) {
+        if !filter.is_narrowed() {
+            return Err(PlankaError::Invalid(format!(
+                "the filter selects every card on board {}; add list, label or name_contains, \
+                 or set expected_count to the number of cards ({selected})",
+                filter.board_id
+            )));
+        }
+    }
+    match expected_count {
+        Some(expected) if expected != selected => Err(PlankaError::Invalid(format!(
+            "expected {expected} card(s) but the selection matches {selected}; nothing was deleted"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+impl PlankaClient {
+    /// Resolves a selection to card ids, without duplicates
+    pub async fn select_cards(&self, selection: &CardSelection) -> Result<Vec<String>, PlankaError> {
+        match selection {
+            CardSelection::Ids(ids) => {
+                let mut unique: Vec<String> = Vec::with_capacity(ids.len());
+                for id in ids {
+                    if !unique.contains(id) {
+                        unique.push(id.clone());
+                    }
+                }
+                Ok(unique)
+            }
+            CardSelection::Filter(filter) => {
+                let data = self.get_board(&filter.board_id, true).await?;
+                let ids = filter.matching_cards(&data)?;
+                debug!(board_id = %filter.board_id, matched = ids.len(), "Selected cards by filter");
+                Ok(ids)
+            }
+        }
+    }
+
+    /// Checks the scope of the selected cards with one lookup of the allowed
+    /// boards, instead of one per card, resolving the cards' boards at most
+    /// `concurrency` at a time. Returns the reason for every card that cannot
+    /// be changed.
+    async fn out_of_scope_cards(
+        &self,
+        selection: &CardSelection,
+        ids: &[String],
+        concurrency: usize,
+    ) -> Result<HashMap<String, String>, PlankaError> {
+        let mut rejected = HashMap::new();
+        // A filter reads its board through get_board, which checks its scope
+        if self.scope.is_unrestricted() || matches!(selection, CardSelection::Filter(_)) {
+            return Ok(rejected);
+        }
+        let allowed = self.allowed_board_ids().await?;
+        let board_ids: Vec<(String, Result<String, PlankaError>)> = stream::iter(ids.iter().cloned())
+            .map(|card_id| async move {
+                let board_id = self.card_board_id(&card_id).await;
+                (card_id, board_id)
+            })
+            .buffered(concurrency.clamp(1, MAX_BULK_CONCURRENCY))
+            .collect()
+            .await;
+        for (card_id, board_id) in board_ids {
+            match board_id {
+                Ok(board_id) if allowed.contains(&board_id) => {}
+                Ok(board_id) => {
+                    warn!(card_id = %card_id, board_id = %board_id, "Rejected bulk change of card outside configured scope");
+                    rejected.insert(
+                        card_id,
+                        PlankaError::OutOfScope(format!("board {board_id} is outside the configured scope")).to_string(),
+                    );
+                }
+                Err(e) => {
+                    rejected.insert(card_id, e.to_string());
+                }
+            }
+        }
+        debug!(cards = ids.len(), rejected = rejected.len(), "Checked scope of selected cards");
+        Ok(rejected)
+    }
+
+    async fn card_board_id(&self, card_id: &str) -> Result<String, PlankaError> {
+        let card = self.fetch_card(card_id).await?;
+        match card.board_id {
+            Some(board_id) => Ok(board_id),
+            None => Ok(self.fetch_list(&card.list_id).await?.item.board_id),
+        }
+    }
+
+    /// Runs `op` for every card in scope, at most `concurrency` at a time,
+    /// and collects the outcome of each
+    async fn run_bulk<F, Fut>(
+        &self,
+        action: &str,
+        ids: Vec<String>,
+        rejected: HashMap<String, String>,
+        concurrency: usize,
+        op: F,
+    ) -> BulkReport
+    where
+        F: Fn(usize, String) -> Fut,
+        Fut: Future<Output = Result<(), PlankaError>>,
+    {
+        let concurrency = concurrency.clamp(1, MAX_BULK_CONCURRENCY);
+        info!(action = %action, cards = ids.len(), concurrency, "Running bulk card operation");
+        let results: Vec<BulkCardResult> = stream::iter(ids.into_iter().enumerate())
+            .map(|(i, card_id)| {
+                let skipped = rejected.get(&card_id).cloned();
+                let outcome = skipped.is_none().then(|| op(i, card_id.clone()));
+                async move {
+                    let outcome = match (outcome, skipped) {
+                        (Some(outcome), _) => outcome.await.map_err(|e| e.to_string()),
+                        (None, reason) => Err(reason.unwrap_or_default()),
+                    };
+                    match outcome {
+                        Ok(()) => BulkCardResult { card_id, ok: true, error: None },
+                        Err(e) => {
+                            warn!(action = %action, card_id = %card_id, error = %e, "Bulk operation failed for card");
+                            BulkCardResult { card_id, ok: false, error: Some(e) }
+                        }
+                    }
+                }
+            })
+            .buffered(concurrency)
+            .collect()
+            .await;
+
+        let succeeded = results.iter().filter(|r| r.ok).count();
+        let report = BulkReport {
+            matched: results.len(),
+            succeeded,
+            failed: results.len() - succeeded,
+            results,
+        };
+        info!(action = %action, succeeded = report.succeeded, failed = report.failed, "Bulk card operation finished");
+        report
+    }
+
+    /// Applies the same update to every selected card
+    pub async fn bulk_update_cards(
+        &self,
+        selection: &CardSelection,
+        options: UpdateCardOptions,
+        concurrency: usize,
+    ) -> Result<BulkReport, PlankaError> {
+        if let Some(board_id) = options.board_id.as_deref() {
+            self.ensure_board_in_scope(board_id).await?;
+        }
+        let ids = self.select_cards(selection).await?;
+        let rejected = self.out_of_scope_cards(selection, &ids, concurrency).await?;
+        let report = self
+            .run_bulk("update", ids, rejected, concurrency, |_, card_id| {
+                let options = options.clone();
+                async move { self.update_card_in_scope(&card_id, options).await.map(|_| ()) }
+            })
+            .await;
+        Ok(report)
+    }
+
+    /// Moves every selected card to the top or bottom of a list, keeping
+    /// the selection order
+    pub async fn bulk_move_cards(
+        &self,
+        selection: &CardSelection,
+        list_id: &str,
+        placement: Placement,
+        concurrency: usize,
+    ) -> Result<BulkReport, PlankaError> {
+        self.ensure_list_in_scope(list_id).await?;
+        let ids = self.select_cards(selection).await?;
+        let rejected = self.out_of_scope_cards(selection, &ids, concurrency).await?;
+        let list = self.fetch_list(list_id).await?;
+        let siblings: Vec<(&str, Option<f64>)> = list
+            .included
+            .cards
+            .iter()
+            .map(|c| (c.id.as_str(), c.position))
+            .collect();
+        let Spread { positions, renumber } = spread_positions(&siblings, &ids, ids.len(), &placement)?;
+        debug!(list_id = %list_id, placement = ?placement, cards = ids.len(), "Computed bulk move positions");
+        if !renumber.is_empty() {
+            let renumbered = self.renumber_positions("cards", &renumber).await;
+            self.cache.invalidate_list(list_id);
+            renumbered?;
+        }
+
+        let report = self
+            .run_bulk("move", ids, rejected, concurrency, |i, card_id| {
+                let position = positions[i];
+                async move {
+                    self.move_card_in_scope(&card_id, list_id, Placement::At(position))
+                        .await
+                        .map(|_| ())
+                }
+            })
+            .await;
+        Ok(report)
+    }
+
+    /// Deletes every selected card, after [`check_delete_guard`] passes
+    pub async fn bulk_delete_cards(
+        &self,
+        selection: &CardSelection,
+        expected_count: Option<usize>,
+        concurrency: usize,
+    ) -> Result<BulkReport, PlankaError> {
+        let ids = self.select_cards(selection).await?;
+        check_delete_guard(selection, ids.len(), expected_count)?;
+        let rejected = self.out_of_scope_cards(selection, &ids, concurrency).await?;
+        let report = self
+            .run_bulk("delete", ids, rejected, concurrency, |_, card_id| async move {
+                self.delete_card_in_scope(&card_id).await
+            })
+            .await;
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::planka::position::POSITION_GAP;
+    use crate::planka::test_support::BoardBuilder;
+
+    fn board() -> BoardResponse {
+        BoardBuilder::new("Sprint")
+            .list("b", "Done", Some(2.0))
+            .list("a", "Review", Some(1.0))
+            .card("12", "a", "Fix login", Some(2.0))
+            .card("11", "a", "Docs", Some(1.0))
+            .card("13", "b", "Login page", Some(1.0))
+            .label("50", "bug", "berry-red", None)
+            .card_label("70", "12", "50")
+            .build()
+    }
+
+    fn filter(list: Option<&str>, label: Option<&str>, name_contains: Option<&str>) -> CardFilter {
+        CardFilter {
+            board_id: "1".to_string(),
+            list: list.map(str::to_string),
+            label: label.map(str::to_string),
+            name_contains: name_contains.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_filter_matches_cards_in_board_order() {
+        let data = board();
+        assert_eq!(filter(None, None, None).matching_cards(&data).unwrap(), ["11", "12", "13"]);
+        assert_eq!(filter(Some("Review"), None, None).matching_cards(&data).unwrap(), ["11", "12"]);
+        assert_eq!(filter(None, Some("bug"), None).matching_cards(&data).unwrap(), ["12"]);
+        assert_eq!(filter(Some("b"), None, Some("LOGIN")).matching_cards(&data).unwrap(), ["13"]);
+        assert!(filter(Some("Backlog"), None, None).matching_cards(&data).is_err());
+    }
+
+    #[test]
+    fn test_spread_positions_keeps_selection_order() {
+        let siblings = [("1", Some(100.0)), ("2", Some(200.0)), ("3", Some(50.0))];
+        let moving = vec!["3".to_string(), "9".to_string()];
+        let bottom = spread_positions(&siblings, &moving, 2, &Placement::Bottom).unwrap();
+        assert_eq!(bottom.positions, [200.0 + POSITION_GAP, 200.0 + 2.0 * POSITION_GAP]);
+        assert!(bottom.renumber.is_empty());
+        let top = spread_positions(&siblings, &moving, 3, &Placement::Top).unwrap();
+        assert_eq!(top.positions, [50.0, 75.0, 87.5]);
+        assert!(top.renumber.is_empty());
+        let top = spread_positions(&[], &[], 1, &Placement::Top).unwrap();
+        assert_eq!(top.positions, [POSITION_GAP]);
+    }
+
+    #[test]
+    fn test_spread_positions_renumbers_when_the_first_sibling_is_at_zero() {
+        for first in [Some(0.0), None] {
+            let siblings = [("1", first), ("2", Some(POSITION_GAP))];
+            let top = spread_positions(&siblings, &[], 2, &Placement::Top).unwrap();
+            assert_eq!(top.positions, [POSITION_GAP, 1.5 * POSITION_GAP]);
+            assert_eq!(
+                top.renumber,
+                vec![("1".to_string(), 2.0 * POSITION_GAP), ("2".to_string(), 3.0 * POSITION_GAP)]
+            );
+        }
+    }
+
+    #[test]
+    fn test_delete_guard_needs_a_narrowing_condition_or_expected_count() {
+        let whole_board = CardSelection::Filter(filter(None, None, None));
+        assert!(check_delete_guard(&whole_board, 3, None).is_err());
+        assert!(check_delete_guard(&whole_board, 3, Some(3)).is_ok());
+        assert!(check_delete_guard(&whole_board, 3, Some(2)).is_err());
+
+        let narrowed = CardSelection::Filter(filter(Some("Review"), None, None));
+        assert!(check_delete_guard(&narrowed, 2, None).is_ok());
+        assert!(check_delete_guard(&CardSelection::Filter(filter(None, None, Some(""))), 3, None).is_err());
+
+        let ids = CardSelection::Ids(vec!["11".to_string()]);
+        assert!(check_delete_guard(&ids, 1, None).is_ok());
+        assert!(check_delete_guard(&ids, 1, Some(2)).is_err());
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::trace;
+
+use super::types::{BoardResponse, ProjectResponse};
+
+/// Default lifetime of a cached board or project snapshot
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+struct Entry<T> {
+    value: T,
+    fetched_at: Instant,
+}
+
+/// Ids of the projects and boards inside the configured scope, derived from
+/// `GET /api/projects`
+#[derive(Debug, Clone, Default)]
+pub struct AllowedIds {
+    pub projects: HashSet<String>,
+    pub boards: HashSet<String>,
+}
+
+/// Short-lived snapshots of `GET /api/boards/{id}` and `GET /api/projects/{id}`,
+/// and of the ids allowed by the configured scope.
+///
+/// Several read tools are served from the same board response, so a single
+/// conversation turn would otherwise fetch it repeatedly. Writes invalidate
+/// every snapshot they may have changed; a TTL of zero disables caching.
+#[derive(Debug)]
+pub struct SnapshotCache {
+    ttl: Duration,
+    boards: Mutex<HashMap<String, Entry<BoardResponse>>>,
+    projects: Mutex<HashMap<String, Entry<ProjectResponse>>>,
+    allowed: Mutex<HashMap<String, Entry<AllowedIds>>>,
+}
+
+/// Key of the single entry in `SnapshotCache::allowed`
+const ALLOWED_KEY: &str = "scope";
+
+impl SnapshotCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            boards: Mutex::new(HashMap::new()),
+            projects: Mutex::new(HashMap::new()),
+            allowed: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    pub fn board(&self, board_id: &str) -> Option<BoardResponse> {
+        get(&self.boards, board_id, self.ttl)
+    }
+
+    pub fn put_board(&self, board_id: &str, board: BoardResponse) {
+        put(&self.boards, board_id, board, self.ttl);
+    }
+
+    pub fn project(&self, project_id: &str) -> Option<ProjectResponse> {
+        get(&self.projects, project_id, self.ttl)
+    }
+
+    pub fn put_project(&self, project_id: &str, project: ProjectResponse) {
+        put(&self.projects, project_id, project, self.ttl);
+    }
+
+    pub fn allowed_ids(&self) -> Option<AllowedIds> {
+        get(&self.allowed, ALLOWED_KEY, self.ttl)
+    }
+
+    pub fn put_allowed_ids(&self, allowed: AllowedIds) {
+        put(&self.allowed, ALLOWED_KEY, allowed, self.ttl);
+    }
+
+    /// Drops the allowed ids after a project, board or membership changed
+    pub fn invalidate_allowed_ids(&self) {
+        if lock(&self.allowed).remove(ALLOWED_KEY).is_some() {
+            trace!("Invalidated cached allowed ids");
+        }
+    }
+
+    pub fn invalidate_board(&self, board_id: &str) {
+        if lock(&self.boards).remove(board_id).is_some() {
+            trace!(board_id = %board_id, "Invalidated cached board");
+        }
+    }
+
+    pub fn invalidate_project(&self, project_id: &str) {
+        if lock(&self.projects).remove(project_id).is_some() {
+            trace!(project_id = %project_id, "Invalidated cached project");
+        }
+    }
+
+    /// Drops every cached board containing the list
+    pub fn invalidate_list(&self, list_id: &str) {
+        self.invalidate_boards_where(|b| b.included.lists.iter().any(|l| l.id == list_id));
+    }
+
+    /// Drops every cached board containing the card
+    pub fn invalidate_card(&self, card_id: &str) {
+        self.invalidate_boards_where(|b| b.included.cards.iter().any(|c| c.id == card_id));
+    }
+
+    /// Drops every cached board containing the task list
+    pub fn invalidate_task_list(&self, task_list_id: &str) {
+        self.invalidate_boards_where(|b| b.included.task_lists.iter().any(|t| t.id == task_list_id));
+    }
+
+    fn invalidate_boards_where(&self, stale: impl Fn(&BoardResponse) -> bool) {
+        lock(&self.boards).retain(|board_id, entry| {
+            let keep = !stale(&entry.value);
+            if !keep {
+                trace!(board_id = %board_id, "Invalidated cached board");
+            }
+            keep
+        });
+    }
+}
+
+fn lock<T>(map: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    // A panic while holding the lock cannot leave a map half-updated
+    map.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn get<T: Clone>(map: &Mutex<HashMap<String, Entry<T>>>, id: &str, ttl: Duration) -> Option<T> {
+    let mut map = lock(map);
+    match map.get(id) {
+        Some(entry) if entry.fetched_at.elapsed() < ttl => {
+            trace!(id = %id, "Snapshot cache hit");
+            Some(entry.value.clone())
+        }
+        Some(_) => {
+            trace!(id = %id, "Snapshot cache entry expired");
+            map.remove(id);
+            None
+        }
+        None => None,
+    }
+}
+
+fn put<T>(map: &Mutex<HashMap<String, Entry<T>>>, id: &str, value: T, ttl: Duration) {
+    if ttl.is_zero() {
+        return;
+    }
+    lock(map).insert(
+        id.to_string(),
+        Entry {
+            value,
+            fetched_at: Instant::now(),
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::planka::test_support::BoardBuilder;
+
+    fn board() -> BoardResponse {
+        BoardBuilder::new("Sprint").list("10", "Todo", None).card("100", "10", "Task", None).build()
+    }
+
+    #[test]
+    fn test_invalidation_by_contained_ids() {
+        let cache = SnapshotCache::new(Duration::from_secs(60));
+        cache.put_board("1", board());
+        cache.invalidate_card("999");
+        assert!(cache.board("1").is_some());
+        cache.invalidate_card("100");
+        assert!(cache.board("1").is_none());
+
+        cache.put_board("1", board());
+        cache.invalidate_list("10");
+        assert!(cache.board("1").is_none());
+    }
+
+    #[test]
+    fn test_zero_ttl_disables_cache() {
+        let cache = SnapshotCache::new(Duration::ZERO);
+        cache.put_board("1", board());
+        assert!(cache.board("1").is_none());
+        cache.put_allowed_ids(AllowedIds::default());
+        assert!(cache.allowed_ids().is_none());
+    }
+
+    #[test]
+    fn test_allowed_ids_invalidation() {
+        let cache = SnapshotCache::new(Duration::from_secs(60));
+        let allowed = AllowedIds {
+            projects: HashSet::from(["1".to_string()]),
+            boards: HashSet::from(["2".to_string()]),
+        };
+        cache.put_allowed_ids(allowed);
+        assert!(cache.allowed_ids().is_some_and(|a| a.boards.contains("2")));
+        cache.invalidate_allowed_ids();
+        assert!(cache.allowed_ids().is_none());
+    }
+}
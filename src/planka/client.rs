@@ -1,16 +1,28 @@
-use reqwest::{Client, header};
+use reqwest::{Client, NoProxy, Proxy, header};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, trace, warn};
 use url::Url;
 
+use super::cache::{AllowedIds, SnapshotCache, DEFAULT_CACHE_TTL};
+use super::config::ClientConfig;
+use super::position::{position_for, reorder, Placement};
+use super::scope::Scope;
+use super::tls;
 use super::types::*;
 
 #[derive(Debug, Error)]
 pub enum PlankaError {
     #[error("HTTP error: {0}")]
-    Http(#[from] reqwest::Error),
+    Http(reqwest::Error),
+
+    #[error("Timeout: the Planka server did not respond in time ({0})")]
+    Timeout(reqwest::Error),
 
     #[error("HTTP status {0}: {1}")]
     Status(u16, String),
@@ -20,12 +32,34 @@ pub enum PlankaError {
 
     #[error("JSON error: {0}")]
     Serde(#[from] serde_json::Error),
+
+    #[error("Out of scope: {0}")]
+    OutOfScope(String),
+
+    #[error("Invalid request: {0}")]
+    Invalid(String),
 }
 
 #[derive(Debug, Clone)]
 enum PlankaAuth {
     Token(String),
+    /// Token re-read from a file on every request, so it can be rotated in place
+    TokenFile(PathBuf),
+    /// Token printed by an external command, reused for `ttl`
+    TokenCommand { command: String, ttl: Duration },
     Credentials { email: String, password: String },
+    /// No credentials of its own; only clients derived with a user token can
+    /// make requests
+    Forwarded,
+}
+
+/// Default lifetime of a token obtained from a credential helper
+const DEFAULT_TOKEN_COMMAND_TTL: Duration = Duration::from_secs(300);
+
+#[derive(Debug, Clone)]
+struct CachedToken {
+    value: String,
+    fetched_at: Instant,
 }
 
 #[derive(Debug)]
@@ -33,16 +67,28 @@ pub struct PlankaClient {
     base_url: Url,
     http: Client,
     auth: PlankaAuth,
-    cached_token: Arc<RwLock<Option<String>>>,
+    cached_token: Arc<RwLock<Option<CachedToken>>>,
+    token_cache_file: Option<PathBuf>,
+    pub(super) scope: Scope,
+    pub(super) cache: Arc<SnapshotCache>,
 }
 
 impl PlankaClient {
-    pub fn from_env() -> Result<Self, PlankaError> {
-        debug!("Initializing Planka client from environment variables");
-        
-        let base_url = std::env::var("PLANKA_URL")
-            .map_err(|_| {
-                error!("PLANKA_URL environment variable not set");
+    pub fn from_config(config: &ClientConfig) -> Result<Self, PlankaError> {
+        Self::build(config, true)
+    }
+
+    /// Builds a client whose own credentials are optional, for HTTP mode where
+    /// every session forwards a user token through [`Self::with_token`]
+    pub fn for_forwarding(config: &ClientConfig) -> Result<Self, PlankaError> {
+        Self::build(config, false)
+    }
+
+    fn build(config: &ClientConfig, require_auth: bool) -> Result<Self, PlankaError> {
+        debug!("Initializing Planka client");
+        let base_url = config.url.clone()
+            .ok_or_else(|| {
+                error!("No Planka URL configured");
                 PlankaError::Config("PLANKA_URL not set".into())
             })?;
 
@@ -53,32 +99,51 @@ impl PlankaClient {
                 PlankaError::Config(format!("Invalid PLANKA_URL: {e}"))
             })?;
 
-        let auth = if let Ok(token) = std::env::var("PLANKA_TOKEN") {
+        let has_credentials = config.token.is_some()
+            || config.token_file.is_some()
+            || config.token_command.is_some()
+            || config.email.is_some();
+
+        let auth = if !require_auth && !has_credentials {
+            debug!("No service credentials configured, relying on forwarded user tokens");
+            PlankaAuth::Forwarded
+        } else if let Some(token) = config.token.clone() {
             debug!("Using token-based authentication");
             PlankaAuth::Token(token)
+        } else if let Some(path) = config.token_file.clone() {
+            debug!(path = %path.display(), "Using token from file");
+            read_secret_file(&path)?;
+            PlankaAuth::TokenFile(path)
+        } else if let Some(command) = config.token_command.clone() {
+            let ttl = config
+                .token_command_ttl_secs
+                .map(Duration::from_secs)
+                .unwrap_or(DEFAULT_TOKEN_COMMAND_TTL);
+            debug!(ttl_secs = ttl.as_secs(), "Using token from credential helper");
+            PlankaAuth::TokenCommand { command, ttl }
         } else {
             debug!("Using email/password authentication");
-            let email = std::env::var("PLANKA_EMAIL")
-                .map_err(|_| {
-                    error!("Neither PLANKA_TOKEN nor PLANKA_EMAIL is set");
-                    PlankaError::Config("PLANKA_TOKEN or PLANKA_EMAIL must be set".into())
+            let email = config.email.clone()
+                .ok_or_else(|| {
+                    error!("Neither a token source nor PLANKA_EMAIL is set");
+                    PlankaError::Config(
+                        "PLANKA_TOKEN, PLANKA_TOKEN_FILE, PLANKA_TOKEN_COMMAND or PLANKA_EMAIL must be set".into(),
+                    )
                 })?;
-            let password = std::env::var("PLANKA_PASSWORD")
-                .map_err(|_| {
+            let password = match (&config.password, &config.password_file) {
+                (Some(password), _) => Some(password.clone()),
+                (None, Some(path)) => Some(read_secret_file(path)?),
+                (None, None) => None,
+            };
+            let password = password
+                .ok_or_else(|| {
                     error!("PLANKA_PASSWORD not set but PLANKA_EMAIL is configured");
-                    PlankaError::Config("PLANKA_PASSWORD must be set when using PLANKA_EMAIL".into())
+                    PlankaError::Config("PLANKA_PASSWORD or PLANKA_PASSWORD_FILE must be set when using PLANKA_EMAIL".into())
                 })?;
             PlankaAuth::Credentials { email, password }
         };
 
-        // Check for DISABLE_SSL environment variable
-        let disable_ssl = std::env::var("DISABLE_SSL")
-            .ok()
-            .map(|v| {
-                // Accept various boolean representations (case-insensitive)
-                matches!(v.to_lowercase().as_str(), "true" | "1" | "yes" | "on")
-            })
-            .unwrap_or(false);
+        let disable_ssl = config.disable_ssl.unwrap_or(false);
 
         if disable_ssl {
             warn!(
@@ -91,14 +156,63 @@ impl PlankaClient {
 
         debug!("Building HTTP client with SSL verification {}", if disable_ssl { "disabled" } else { "enabled" });
         
-        let http = Client::builder()
-            .danger_accept_invalid_certs(disable_ssl)
+        let mut builder = tls::configure(Client::builder(), config)?;
+        if let Some(secs) = config.timeout_secs {
+            debug!(timeout_secs = secs, "Setting request timeout");
+            builder = builder.timeout(Duration::from_secs(secs));
+        }
+        if let Some(secs) = config.connect_timeout_secs {
+            debug!(connect_timeout_secs = secs, "Setting connect timeout");
+            builder = builder.connect_timeout(Duration::from_secs(secs));
+        }
+        if let Some(secs) = config.read_timeout_secs {
+            debug!(read_timeout_secs = secs, "Setting read timeout");
+            builder = builder.read_timeout(Duration::from_secs(secs));
+        }
+        if let Some(proxy_url) = config.proxy.as_deref() {
+            let proxy = Proxy::all(proxy_url)
+                .map_err(|e| {
+                    error!(proxy = %proxy_url, error = %e, "Invalid proxy URL");
+                    PlankaError::Config(format!("Invalid proxy URL: {e}"))
+                })?
+                .no_proxy(NoProxy::from_string(&config.no_proxy.join(",")));
+            info!(proxy = %proxy_url, no_proxy = ?config.no_proxy, "Routing requests through proxy");
+            builder = builder.proxy(proxy);
+        }
+        if let Some(max) = config.pool_max_idle_per_host {
+            debug!(pool_max_idle_per_host = max, "Setting connection pool size");
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(secs) = config.pool_idle_timeout_secs {
+            debug!(pool_idle_timeout_secs = secs, "Setting connection pool idle timeout");
+            builder = builder.pool_idle_timeout(Duration::from_secs(secs));
+        }
+        if let Some(secs) = config.tcp_keepalive_secs {
+            debug!(tcp_keepalive_secs = secs, "Setting TCP keepalive");
+            builder = builder.tcp_keepalive(Duration::from_secs(secs));
+        }
+        let http = builder
             .build()
             .map_err(|e| {
                 error!(error = %e, "Failed to build HTTP client");
                 PlankaError::Http(e)
             })?;
 
+        let scope = Scope::new(config.projects.clone(), config.boards.clone());
+        if !scope.is_unrestricted() {
+            info!(
+                projects = ?scope.projects(),
+                boards = ?scope.boards(),
+                "Restricting client to configured projects and boards"
+            );
+        }
+
+        let cache_ttl = config
+            .cache_ttl_secs
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_CACHE_TTL);
+        debug!(cache_ttl_secs = cache_ttl.as_secs(), "Configuring board snapshot cache");
+
         info!(
             base_url = %base_url,
             ssl_validation = !disable_ssl,
@@ -109,22 +223,87 @@ impl PlankaClient {
             http,
             auth,
             cached_token: Arc::new(RwLock::new(None)),
+            token_cache_file: config.token_cache_file.clone(),
+            scope,
+            cache: Arc::new(SnapshotCache::new(cache_ttl)),
         })
     }
 
+    /// Returns a client for the same server that authenticates with the given
+    /// bearer token instead, e.g. one forwarded on behalf of an end user
+    pub fn with_token(&self, token: impl Into<String>) -> Self {
+        Self {
+            base_url: self.base_url.clone(),
+            http: self.http.clone(),
+            auth: PlankaAuth::Token(token.into()),
+            cached_token: Arc::new(RwLock::new(None)),
+            token_cache_file: None,
+            scope: self.scope.clone(),
+            // Snapshots are not shared, as another token may see different data
+            cache: Arc::new(SnapshotCache::new(self.cache.ttl())),
+        }
+    }
+
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
     async fn get_token(&self) -> Result<String, PlankaError> {
         match &self.auth {
             PlankaAuth::Token(token) => {
                 trace!("Using configured bearer token");
                 Ok(token.clone())
             }
+            PlankaAuth::Forwarded => {
+                error!("Request attempted without a forwarded user token");
+                Err(PlankaError::Config("No Planka credentials configured; a user token must be provided".into()))
+            }
+            PlankaAuth::TokenFile(path) => {
+                trace!(path = %path.display(), "Reading bearer token from file");
+                read_secret_file(path)
+            }
+            PlankaAuth::TokenCommand { command, ttl } => {
+                {
+                    let cache = self.cached_token.read().await;
+                    if let Some(token) = cache.as_ref().filter(|t| t.fetched_at.elapsed() < *ttl) {
+                        trace!("Using cached credential helper token");
+                        return Ok(token.value.clone());
+                    }
+                }
+
+                let token = run_token_command(command).await?;
+                let mut cache = self.cached_token.write().await;
+                *cache = Some(CachedToken {
+                    value: token.clone(),
+                    fetched_at: Instant::now(),
+                });
+                Ok(token)
+            }
             PlankaAuth::Credentials { email, password } => {
                 // Check cache first
                 {
                     let cache = self.cached_token.read().await;
                     if let Some(token) = cache.as_ref() {
                         trace!("Using cached authentication token");
-                        return Ok(token.clone());
+                        return Ok(token.value.clone());
+                    }
+                }
+
+                // Hold the write lock while logging in so that concurrent
+                // requests share one access token instead of creating several
+                let mut cache = self.cached_token.write().await;
+                if let Some(token) = cache.as_ref() {
+                    trace!("Using authentication token obtained by a concurrent request");
+                    return Ok(token.value.clone());
+                }
+
+                if let Some(path) = self.token_cache_file.as_deref() {
+                    if let Some(token) = self.load_persisted_token(path).await {
+                        *cache = Some(CachedToken {
+                            value: token.clone(),
+                            fetched_at: Instant::now(),
+                        });
+                        return Ok(token);
                     }
                 }
 
@@ -178,9 +357,12 @@ impl PlankaClient {
                     })?;
 
                 // Cache the token
-                {
-                    let mut cache = self.cached_token.write().await;
-                    *cache = Some(token.clone());
+                *cache = Some(CachedToken {
+                    value: token.clone(),
+                    fetched_at: Instant::now(),
+                });
+                if let Some(path) = self.token_cache_file.as_deref() {
+                    persist_token(path, &token);
                 }
 
                 info!("Authentication successful, token cached");
@@ -189,6 +371,76 @@ impl PlankaClient {
         }
     }
 
+    /// Reads a token saved by a previous run and checks that it is still accepted
+    async fn load_persisted_token(&self, path: &Path) -> Option<String> {
+        let token = std::fs::read_to_string(path).ok()?.trim().to_string();
+        if token.is_empty() {
+            return None;
+        }
+
+        debug!(path = %path.display(), "Validating persisted access token");
+        let url = self.base_url.join("/api/users/me").ok()?;
+        match self.http.get(url).bearer_auth(&token).send().await {
+            Ok(resp) if resp.status().is_success() => {
+                info!(path = %path.display(), "Reusing persisted access token");
+                Some(token)
+            }
+            Ok(resp) => {
+                info!(status = resp.status().as_u16(), "Persisted access token was rejected, logging in again");
+                None
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to validate persisted access token");
+                None
+            }
+        }
+    }
+
+    /// Revokes the access token created for email/password authentication.
+    ///
+    /// Does nothing for other authentication methods, when no token was
+    /// created yet, or when the token is persisted for the next run.
+    pub async fn logout(&self) -> Result<(), PlankaError> {
+        if !matches!(self.auth, PlankaAuth::Credentials { .. }) {
+            return Ok(());
+        }
+        if self.token_cache_file.is_some() {
+            debug!("Keeping persisted access token for the next run");
+            return Ok(());
+        }
+        let Some(token) = self.cached_token.write().await.take() else {
+            trace!("No access token to revoke");
+            return Ok(());
+        };
+
+        info!("Revoking access token");
+        let url = self.base_url.join("/api/access-tokens/me")?;
+        let resp = self.http
+            .delete(url.clone())
+            .bearer_auth(&token.value)
+            .send()
+            .await
+            .map_err(|e| {
+                error!(url = %url, error = %e, "Failed to send logout request");
+                e
+            })?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let body = resp.text().await.unwrap_or_default();
+            error!(
+                status = status_code,
+                response_body = %body,
+                "Logout failed"
+            );
+            return Err(PlankaError::Status(status_code, body));
+        }
+
+        info!("Access token revoked");
+        Ok(())
+    }
+
     async fn request(&self, method: reqwest::Method, path: &str) -> Result<reqwest::RequestBuilder, PlankaError> {
         trace!(method = %method, path = %path, "Preparing API request");
         let token = self.get_token().await?;
@@ -200,8 +452,31 @@ impl PlankaClient {
             .header(header::AUTHORIZATION, format!("Bearer {token}")))
     }
 
+    /// Returns the user the client authenticates as
+    pub async fn current_user(&self) -> Result<User, PlankaError> {
+        let data = self.get_value("/api/users/me").await?;
+        let user: User = serde_json::from_value(data["item"].clone())?;
+        debug!(user_id = %user.id, "Resolved current user");
+        Ok(user)
+    }
+
     pub async fn list_projects(&self) -> Result<Vec<Project>, PlankaError> {
         debug!("Listing all projects");
+        let data = self.fetch_projects().await?;
+        let total = data.items.len();
+        let projects: Vec<Project> = data
+            .items
+            .iter()
+            .filter(|p| self.project_visible(p, &data.included.boards))
+            .cloned()
+            .collect();
+
+        info!(count = projects.len(), hidden = total - projects.len(), "Successfully listed projects");
+        trace!(projects = ?projects, "Project details");
+        Ok(projects)
+    }
+
+    async fn fetch_projects(&self) -> Result<ProjectsResponse, PlankaError> {
         let resp = self.request(reqwest::Method::GET, "/api/projects")
             .await?
             .send()
@@ -228,14 +503,169 @@ impl PlankaClient {
             error!(error = %e, path = "/api/projects", "Failed to parse response JSON");
             e
         })?;
-        
-        info!(count = data.items.len(), "Successfully listed projects");
-        trace!(projects = ?data.items, "Project details");
-        Ok(data.items)
+        Ok(data)
+    }
+
+    /// A project is visible when it passes the project filter and, if a board
+    /// filter is configured, contains at least one allowed board.
+    fn project_visible(&self, project: &Project, boards: &[Board]) -> bool {
+        self.scope.allows_project(project)
+            && (self.scope.boards().is_empty()
+                || boards.iter().any(|b| {
+                    b.project_id.as_deref() == Some(project.id.as_str()) && self.scope.allows_board(b)
+                }))
+    }
+
+    /// Returns the projects and boards inside the configured scope, from the
+    /// cache when a recent lookup is still valid
+    async fn allowed_ids(&self) -> Result<AllowedIds, PlankaError> {
+        if let Some(allowed) = self.cache.allowed_ids() {
+            return Ok(allowed);
+        }
+        let data = self.fetch_projects().await?;
+        let projects: HashSet<String> = data
+            .items
+            .iter()
+            .filter(|p| self.scope.allows_project(p))
+            .map(|p| p.id.clone())
+            .collect();
+        let boards = data
+            .included
+            .boards
+            .iter()
+            .filter(|b| {
+                b.project_id.as_deref().is_some_and(|id| projects.contains(id)) && self.scope.allows_board(b)
+            })
+            .map(|b| b.id.clone())
+            .collect();
+
+        let allowed = AllowedIds { projects, boards };
+        debug!(projects = allowed.projects.len(), boards = allowed.boards.len(), "Resolved allowed ids");
+        self.cache.put_allowed_ids(allowed.clone());
+        Ok(allowed)
+    }
+
+    pub(super) async fn allowed_board_ids(&self) -> Result<HashSet<String>, PlankaError> {
+        Ok(self.allowed_ids().await?.boards)
+    }
+
+    pub(super) async fn ensure_project_in_scope(&self, project_id: &str) -> Result<(), PlankaError> {
+        if self.scope.is_unrestricted() {
+            return Ok(());
+        }
+        if self.allowed_ids().await?.projects.contains(project_id) {
+            trace!(project_id = %project_id, "Project is in scope");
+            return Ok(());
+        }
+        warn!(project_id = %project_id, "Rejected access to project outside configured scope");
+        Err(PlankaError::OutOfScope(format!("project {project_id} is outside the configured scope")))
+    }
+
+    pub(super) async fn ensure_board_in_scope(&self, board_id: &str) -> Result<(), PlankaError> {
+        if self.scope.is_unrestricted() {
+            return Ok(());
+        }
+        if self.allowed_board_ids().await?.contains(board_id) {
+            trace!(board_id = %board_id, "Board is in scope");
+            return Ok(());
+        }
+        warn!(board_id = %board_id, "Rejected access to board outside configured scope");
+        Err(PlankaError::OutOfScope(format!("board {board_id} is outside the configured scope")))
+    }
+
+    pub(super) async fn ensure_list_in_scope(&self, list_id: &str) -> Result<(), PlankaError> {
+        if self.scope.is_unrestricted() {
+            return Ok(());
+        }
+        let list = self.fetch_list(list_id).await?;
+        self.ensure_board_in_scope(&list.item.board_id).await
+    }
+
+    pub(super) async fn ensure_card_in_scope(&self, card_id: &str) -> Result<(), PlankaError> {
+        if self.scope.is_unrestricted() {
+            return Ok(());
+        }
+        let card = self.fetch_card(card_id).await?;
+        match card.board_id {
+            Some(board_id) => self.ensure_board_in_scope(&board_id).await,
+            None => self.ensure_list_in_scope(&card.list_id).await,
+        }
+    }
+
+    /// Fetches a list together with its cards
+    pub(super) async fn fetch_list(&self, list_id: &str) -> Result<ListResponse, PlankaError> {
+        let path = format!("/api/lists/{list_id}");
+        let resp = self.request(reqwest::Method::GET, &path)
+            .await?
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, path = %path, "Failed to send request");
+                e
+            })?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let body = resp.text().await.unwrap_or_default();
+            error!(
+                status = status_code,
+                path = %path,
+                response_body = %body,
+                "API request failed"
+            );
+            return Err(PlankaError::Status(status_code, body));
+        }
+
+        let data: ListResponse = resp.json().await.map_err(|e| {
+            error!(error = %e, path = %path, "Failed to parse response JSON");
+            e
+        })?;
+        trace!(list = ?data.item, cards = data.included.cards.len(), "Fetched list");
+        Ok(data)
+    }
+
+    pub(super) async fn fetch_card(&self, card_id: &str) -> Result<Card, PlankaError> {
+        let path = format!("/api/cards/{card_id}");
+        let resp = self.request(reqwest::Method::GET, &path)
+            .await?
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, path = %path, "Failed to send request");
+                e
+            })?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let body = resp.text().await.unwrap_or_default();
+            error!(
+                status = status_code,
+                path = %path,
+                response_body = %body,
+                "API request failed"
+            );
+            return Err(PlankaError::Status(status_code, body));
+        }
+
+        let data: CardResponse = resp.json().await.map_err(|e| {
+            error!(error = %e, path = %path, "Failed to parse response JSON");
+            e
+        })?;
+        trace!(card = ?data.item, "Fetched card");
+        Ok(data.item)
     }
 
-    pub async fn list_boards(&self, project_id: &str) -> Result<Vec<Board>, PlankaError> {
-        debug!(project_id = %project_id, "Listing boards for project");
+    /// Fetches a project with its boards, served from the snapshot cache
+    /// unless `refresh` is set
+    async fn fetch_project(&self, project_id: &str, refresh: bool) -> Result<ProjectResponse, PlankaError> {
+        if !refresh {
+            if let Some(data) = self.cache.project(project_id) {
+                debug!(project_id = %project_id, "Using cached project snapshot");
+                return Ok(data);
+            }
+        }
         let path = format!("/api/projects/{project_id}");
         let resp = self.request(reqwest::Method::GET, &path)
             .await?
@@ -263,14 +693,19 @@ impl PlankaClient {
             error!(error = %e, path = %path, "Failed to parse response JSON");
             e
         })?;
-        
-        info!(project_id = %project_id, count = data.included.boards.len(), "Successfully listed boards");
-        trace!(boards = ?data.included.boards, "Board details");
-        Ok(data.included.boards)
+        self.cache.put_project(project_id, data.clone());
+        Ok(data)
     }
 
-    pub async fn list_cards(&self, board_id: &str) -> Result<Vec<Card>, PlankaError> {
-        debug!(board_id = %board_id, "Listing cards for board");
+    /// Fetches a board with its lists and cards, served from the snapshot
+    /// cache unless `refresh` is set
+    pub(super) async fn fetch_board(&self, board_id: &str, refresh: bool) -> Result<BoardResponse, PlankaError> {
+        if !refresh {
+            if let Some(data) = self.cache.board(board_id) {
+                debug!(board_id = %board_id, "Using cached board snapshot");
+                return Ok(data);
+            }
+        }
         let path = format!("/api/boards/{board_id}");
         let resp = self.request(reqwest::Method::GET, &path)
             .await?
@@ -298,16 +733,60 @@ impl PlankaClient {
             error!(error = %e, path = %path, "Failed to parse response JSON");
             e
         })?;
-        
+        self.cache.put_board(board_id, data.clone());
+        Ok(data)
+    }
+
+    pub async fn list_boards(&self, project_id: &str, refresh: bool) -> Result<Vec<Board>, PlankaError> {
+        debug!(project_id = %project_id, refresh, "Listing boards for project");
+        self.ensure_project_in_scope(project_id).await?;
+        let mut data = self.fetch_project(project_id, refresh).await?;
+        data.included.boards.retain(|b| self.scope.allows_board(b));
+
+        info!(project_id = %project_id, count = data.included.boards.len(), "Successfully listed boards");
+        trace!(boards = ?data.included.boards, "Board details");
+        Ok(data.included.boards)
+    }
+
+    pub async fn list_cards(&self, board_id: &str, refresh: bool) -> Result<Vec<Card>, PlankaError> {
+        debug!(board_id = %board_id, refresh, "Listing cards for board");
+        self.ensure_board_in_scope(board_id).await?;
+        let data = self.fetch_board(board_id, refresh).await?;
+
         info!(board_id = %board_id, count = data.included.cards.len(), "Successfully listed cards");
         trace!(cards = ?data.included.cards, "Card details");
         Ok(data.included.cards)
     }
 
-    pub async fn list_lists(&self, board_id: &str) -> Result<Vec<List>, PlankaError> {
-        debug!(board_id = %board_id, "Listing lists for board");
-        let path = format!("/api/boards/{board_id}");
-        let resp = self.request(reqwest::Method::GET, &path)
+    /// Returns a board together with its lists and cards
+    pub async fn get_board(&self, board_id: &str, refresh: bool) -> Result<BoardResponse, PlankaError> {
+        debug!(board_id = %board_id, refresh, "Getting board");
+        self.ensure_board_in_scope(board_id).await?;
+        let data = self.fetch_board(board_id, refresh).await?;
+
+        info!(
+            board_id = %board_id,
+            lists = data.included.lists.len(),
+            cards = data.included.cards.len(),
+            "Successfully fetched board"
+        );
+        Ok(data)
+    }
+
+    /// Returns the board response exactly as sent by Planka, including fields
+    /// this client does not model. Always fetched fresh.
+    pub async fn export_board(&self, board_id: &str) -> Result<serde_json::Value, PlankaError> {
+        info!(board_id = %board_id, "Exporting board");
+        self.ensure_board_in_scope(board_id).await?;
+        let data = self.get_value(&format!("/api/boards/{board_id}")).await?;
+        debug!(board_id = %board_id, "Board exported successfully");
+        Ok(data)
+    }
+
+    /// Fetches a response as untyped JSON, keeping every field. Callers are
+    /// responsible for scope checks.
+    pub(super) async fn get_value(&self, path: &str) -> Result<serde_json::Value, PlankaError> {
+        let resp = self.request(reqwest::Method::GET, path)
             .await?
             .send()
             .await
@@ -329,16 +808,86 @@ impl PlankaClient {
             return Err(PlankaError::Status(status_code, body));
         }
 
-        let data: BoardResponse = resp.json().await.map_err(|e| {
+        let data: serde_json::Value = resp.json().await.map_err(|e| {
             error!(error = %e, path = %path, "Failed to parse response JSON");
             e
         })?;
-        
+        Ok(data)
+    }
+
+    pub async fn list_lists(&self, board_id: &str, refresh: bool) -> Result<Vec<List>, PlankaError> {
+        debug!(board_id = %board_id, refresh, "Listing lists for board");
+        self.ensure_board_in_scope(board_id).await?;
+        let data = self.fetch_board(board_id, refresh).await?;
+
         info!(board_id = %board_id, count = data.included.lists.len(), "Successfully listed lists");
         trace!(lists = ?data.included.lists, "List details");
         Ok(data.included.lists)
     }
 
+    /// Resolves a placement to a position among the cards currently in the list
+    async fn card_position(
+        &self,
+        list_id: &str,
+        placement: &Placement,
+        moving: Option<&str>,
+    ) -> Result<f64, PlankaError> {
+        if let Placement::At(position) = placement {
+            return Ok(*position);
+        }
+        let list = self.fetch_list(list_id).await?;
+        let siblings: Vec<(&str, Option<f64>)> = list
+            .included
+            .cards
+            .iter()
+            .map(|c| (c.id.as_str(), c.position))
+            .collect();
+        let slot = position_for(&siblings, placement, moving)?;
+        if !slot.renumber.is_empty() {
+            let renumbered = self.renumber_positions("cards", &slot.renumber).await;
+            self.cache.invalidate_list(list_id);
+            renumbered?;
+        }
+        debug!(list_id = %list_id, placement = ?placement, position = slot.position, "Computed card position");
+        Ok(slot.position)
+    }
+
+    /// Spreads crowded siblings out again by patching the positions planned
+    /// for them. Callers invalidate the affected cache entries.
+    pub(super) async fn renumber_positions(&self, collection: &str, renumber: &[(String, f64)]) -> Result<(), PlankaError> {
+        info!(collection = %collection, count = renumber.len(), "Renumbering crowded positions");
+        for (id, pos) in renumber {
+            let path = format!("/api/{collection}/{id}");
+            let position = serde_json::Number::from_f64(*pos)
+                .ok_or_else(|| PlankaError::Invalid(format!("position must be a finite number, got {pos}")))?;
+            let body = serde_json::json!({ "position": position });
+
+            let resp = self.request(reqwest::Method::PATCH, &path)
+                .await?
+                .json(&body)
+                .send()
+                .await
+                .map_err(|e| {
+                    error!(error = %e, path = %path, "Failed to send position update request");
+                    e
+                })?;
+
+            let status = resp.status();
+            if !status.is_success() {
+                let status_code = status.as_u16();
+                let body = resp.text().await.unwrap_or_default();
+                error!(
+                    status = status_code,
+                    path = %path,
+                    response_body = %body,
+                    "Position update failed"
+                );
+                return Err(PlankaError::Status(status_code, body));
+            }
+        }
+        Ok(())
+    }
+
     pub async fn create_card(&self, options: CreateCardOptions) -> Result<Card, PlankaError> {
         info!(
             list_id = %options.list_id,
@@ -347,14 +896,16 @@ impl PlankaClient {
             "Creating new card"
         );
         trace!(options = ?options, "Card creation options");
+        self.ensure_list_in_scope(&options.list_id).await?;
         
+        let position = self.card_position(&options.list_id, &options.placement, None).await?;
         let path = format!("/api/lists/{}/cards", options.list_id);
 
         let body = CreateCardRequest {
             card_type: options.card_type,
             name: options.name,
             description: options.description,
-            position: 65535.0, // Default position at end
+            position,
             due_date: options.due_date,
             is_due_completed: options.is_due_completed,
             stopwatch: options.stopwatch,
@@ -390,33 +941,40 @@ impl PlankaClient {
             e
         })?;
         
+        self.cache.invalidate_list(&data.item.list_id);
         info!(card_id = %data.item.id, "Card created successfully");
         trace!(card = ?data.item, "Created card details");
         Ok(data.item)
     }
 
-    pub async fn create_board(
+    pub async fn create_project(
         &self,
-        project_id: &str,
         name: &str,
-    ) -> Result<Board, PlankaError> {
-        info!(project_id = %project_id, name = %name, "Creating new board");
-        let path = format!("/api/projects/{project_id}/boards");
+        project_type: &str,
+        description: Option<String>,
+    ) -> Result<Project, PlankaError> {
+        info!(name = %name, project_type = %project_type, "Creating new project");
+        if !self.scope.allows_project_name("", name) {
+            warn!(name = %name, "Rejected project creation outside configured scope");
+            return Err(PlankaError::OutOfScope(format!("project name {name:?} is outside the configured scope")));
+        }
+        let path = "/api/projects";
 
-        let body = CreateBoardRequest {
+        let body = CreateProjectRequest {
+            project_type: project_type.to_string(),
             name: name.to_string(),
-            position: 65535.0,
+            description,
         };
 
-        trace!(request_body = ?body, "Board creation request");
+        trace!(request_body = ?body, "Project creation request");
 
-        let resp = self.request(reqwest::Method::POST, &path)
+        let resp = self.request(reqwest::Method::POST, path)
             .await?
             .json(&body)
             .send()
             .await
             .map_err(|e| {
-                error!(error = %e, path = %path, "Failed to send board creation request");
+                error!(error = %e, path = %path, "Failed to send project creation request");
                 e
             })?;
 
@@ -428,35 +986,586 @@ impl PlankaClient {
                 status = status_code,
                 path = %path,
                 response_body = %body,
-                "Board creation failed"
+                "Project creation failed"
             );
             return Err(PlankaError::Status(status_code, body));
         }
 
-        let data: BoardCreateResponse = resp.json().await.map_err(|e| {
-            error!(error = %e, path = %path, "Failed to parse board creation response");
+        let data: ProjectItemResponse = resp.json().await.map_err(|e| {
+            error!(error = %e, path = %path, "Failed to parse project creation response");
             e
         })?;
-        
-        info!(board_id = %data.item.id, "Board created successfully");
-        trace!(board = ?data.item, "Created board details");
+
+        self.cache.invalidate_allowed_ids();
+        info!(project_id = %data.item.id, "Project created successfully");
+        trace!(project = ?data.item, "Created project details");
         Ok(data.item)
     }
 
-    pub async fn create_list(
+    pub async fn update_project(
         &self,
-        board_id: &str,
-        name: &str,
-    ) -> Result<List, PlankaError> {
-        info!(board_id = %board_id, name = %name, "Creating new list");
-        let path = format!("/api/boards/{board_id}/lists");
+        project_id: &str,
+        options: UpdateProjectOptions,
+    ) -> Result<Project, PlankaError> {
+        info!(project_id = %project_id, "Updating project");
+        trace!(options = ?options, "Project update options");
+        self.ensure_project_in_scope(project_id).await?;
+        if let Some(name) = options.name.as_deref() {
+            if !self.scope.allows_project_name(project_id, name) {
+                warn!(project_id = %project_id, name = %name, "Rejected project rename outside configured scope");
+                return Err(PlankaError::OutOfScope(format!("project name {name:?} is outside the configured scope")));
+            }
+        }
+        let path = format!("/api/projects/{project_id}");
 
-        let body = CreateListRequest {
-            name: name.to_string(),
-            position: 65535.0,
-        };
+        let mut body = serde_json::Map::new();
+        if let Some(n) = options.name {
+            body.insert("name".to_string(), serde_json::Value::String(n));
+        }
+        if let Some(d) = options.description {
+            body.insert("description".to_string(), serde_json::Value::String(d));
+        }
+        match options.background_gradient {
+            Some(g) if g.is_empty() => {
+                body.insert("backgroundType".to_string(), serde_json::Value::Null);
+            }
+            Some(g) => {
+                body.insert("backgroundType".to_string(), serde_json::Value::String("gradient".to_string()));
+                body.insert("backgroundGradient".to_string(), serde_json::Value::String(g));
+            }
+            None => {}
+        }
+        if body.is_empty() {
+            return Err(PlankaError::Invalid("nothing to update".into()));
+        }
 
-        trace!(request_body = ?body, "List creation request");
+        trace!(request_body = ?body, "Project update request");
+
+        let resp = self.request(reqwest::Method::PATCH, &path)
+            .await?
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, path = %path, "Failed to send project update request");
+                e
+            })?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let body = resp.text().await.unwrap_or_default();
+            error!(
+                status = status_code,
+                path = %path,
+                response_body = %body,
+                "Project update failed"
+            );
+            return Err(PlankaError::Status(status_code, body));
+        }
+
+        let data: ProjectItemResponse = resp.json().await.map_err(|e| {
+            error!(error = %e, path = %path, "Failed to parse project update response");
+            e
+        })?;
+
+        self.cache.invalidate_project(project_id);
+        self.cache.invalidate_allowed_ids();
+        info!(project_id = %project_id, "Project updated successfully");
+        trace!(project = ?data.item, "Updated project details");
+        Ok(data.item)
+    }
+
+    /// Deletes a project with all its boards. `confirm` must repeat the
+    /// project's current name, so a wrong id cannot wipe another project.
+    pub async fn delete_project(&self, project_id: &str, confirm: &str) -> Result<(), PlankaError> {
+        warn!(project_id = %project_id, "Deleting project and all its boards");
+        self.ensure_project_in_scope(project_id).await?;
+        let project = self.fetch_project(project_id, true).await?;
+        if project.item.name != confirm {
+            warn!(project_id = %project_id, "Project deletion not confirmed");
+            return Err(PlankaError::Invalid(format!(
+                "confirm must be the project name {:?} to delete project {project_id}",
+                project.item.name
+            )));
+        }
+        let path = format!("/api/projects/{project_id}");
+
+        let resp = self.request(reqwest::Method::DELETE, &path)
+            .await?
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, path = %path, "Failed to send project deletion request");
+                e
+            })?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let body = resp.text().await.unwrap_or_default();
+            error!(
+                status = status_code,
+                path = %path,
+                response_body = %body,
+                "Project deletion failed"
+            );
+            return Err(PlankaError::Status(status_code, body));
+        }
+
+        self.cache.invalidate_project(project_id);
+        for board in &project.included.boards {
+            self.cache.invalidate_board(&board.id);
+        }
+        self.cache.invalidate_allowed_ids();
+        info!(project_id = %project_id, "Project deleted successfully");
+        Ok(())
+    }
+
+    pub async fn add_project_manager(&self, project_id: &str, user_id: &str) -> Result<ProjectManager, PlankaError> {
+        info!(project_id = %project_id, user_id = %user_id, "Adding project manager");
+        self.ensure_project_in_scope(project_id).await?;
+        let path = format!("/api/projects/{project_id}/project-managers");
+
+        let body = serde_json::json!({ "userId": user_id });
+
+        let resp = self.request(reqwest::Method::POST, &path)
+            .await?
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, path = %path, "Failed to send project manager creation request");
+                e
+            })?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let body = resp.text().await.unwrap_or_default();
+            error!(
+                status = status_code,
+                path = %path,
+                response_body = %body,
+                "Project manager creation failed"
+            );
+            return Err(PlankaError::Status(status_code, body));
+        }
+
+        let data: ProjectManagerResponse = resp.json().await.map_err(|e| {
+            error!(error = %e, path = %path, "Failed to parse project manager creation response");
+            e
+        })?;
+
+        self.cache.invalidate_project(project_id);
+        self.cache.invalidate_allowed_ids();
+        info!(project_manager_id = %data.item.id, "Project manager added successfully");
+        Ok(data.item)
+    }
+
+    /// Removes a user from the managers of a project
+    pub async fn remove_project_manager(&self, project_id: &str, user_id: &str) -> Result<(), PlankaError> {
+        warn!(project_id = %project_id, user_id = %user_id, "Removing project manager");
+        self.ensure_project_in_scope(project_id).await?;
+        let project = self.fetch_project(project_id, true).await?;
+        let manager = project
+            .included
+            .project_managers
+            .iter()
+            .find(|m| m.user_id == user_id)
+            .ok_or_else(|| {
+                PlankaError::Invalid(format!("user {user_id} is not a manager of project {project_id}"))
+            })?;
+        let path = format!("/api/project-managers/{}", manager.id);
+
+        let resp = self.request(reqwest::Method::DELETE, &path)
+            .await?
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, path = %path, "Failed to send project manager deletion request");
+                e
+            })?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let body = resp.text().await.unwrap_or_default();
+            error!(
+                status = status_code,
+                path = %path,
+                response_body = %body,
+                "Project manager deletion failed"
+            );
+            return Err(PlankaError::Status(status_code, body));
+        }
+
+        self.cache.invalidate_project(project_id);
+        self.cache.invalidate_allowed_ids();
+        info!(project_id = %project_id, user_id = %user_id, "Project manager removed successfully");
+        Ok(())
+    }
+
+    pub async fn create_board(
+        &self,
+        project_id: &str,
+        name: &str,
+    ) -> Result<Board, PlankaError> {
+        info!(project_id = %project_id, name = %name, "Creating new board");
+        self.ensure_project_in_scope(project_id).await?;
+        if !self.scope.allows_board_name("", name) {
+            warn!(name = %name, "Rejected board creation outside configured scope");
+            return Err(PlankaError::OutOfScope(format!("board name {name:?} is outside the configured scope")));
+        }
+        let path = format!("/api/projects/{project_id}/boards");
+
+        let body = CreateBoardRequest {
+            name: name.to_string(),
+            position: 65535.0,
+        };
+
+        trace!(request_body = ?body, "Board creation request");
+
+        let resp = self.request(reqwest::Method::POST, &path)
+            .await?
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, path = %path, "Failed to send board creation request");
+                e
+            })?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let body = resp.text().await.unwrap_or_default();
+            error!(
+                status = status_code,
+                path = %path,
+                response_body = %body,
+                "Board creation failed"
+            );
+            return Err(PlankaError::Status(status_code, body));
+        }
+
+        let data: BoardCreateResponse = resp.json().await.map_err(|e| {
+            error!(error = %e, path = %path, "Failed to parse board creation response");
+            e
+        })?;
+        
+        self.cache.invalidate_project(project_id);
+        self.cache.invalidate_allowed_ids();
+        info!(board_id = %data.item.id, "Board created successfully");
+        trace!(board = ?data.item, "Created board details");
+        Ok(data.item)
+    }
+
+    pub async fn update_board(&self, board_id: &str, options: UpdateBoardOptions) -> Result<Board, PlankaError> {
+        info!(board_id = %board_id, "Updating board");
+        trace!(options = ?options, "Board update options");
+        self.ensure_board_in_scope(board_id).await?;
+        if let Some(name) = options.name.as_deref() {
+            if !self.scope.allows_board_name(board_id, name) {
+                warn!(board_id = %board_id, name = %name, "Rejected board rename outside configured scope");
+                return Err(PlankaError::OutOfScope(format!("board name {name:?} is outside the configured scope")));
+            }
+        }
+        let path = format!("/api/boards/{board_id}");
+
+        let mut body = serde_json::Map::new();
+        if let Some(n) = options.name {
+            body.insert("name".to_string(), serde_json::Value::String(n));
+        }
+        if let Some(placement) = options.placement {
+            let pos = self.board_position(board_id, &placement).await?;
+            let position = serde_json::Number::from_f64(pos)
+                .ok_or_else(|| PlankaError::Invalid(format!("position must be a finite number, got {pos}")))?;
+            body.insert("position".to_string(), serde_json::Value::Number(position));
+        }
+        if body.is_empty() {
+            return Err(PlankaError::Invalid("nothing to update".into()));
+        }
+
+        trace!(request_body = ?body, "Board update request");
+
+        let resp = self.request(reqwest::Method::PATCH, &path)
+            .await?
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, path = %path, "Failed to send board update request");
+                e
+            })?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let body = resp.text().await.unwrap_or_default();
+            error!(
+                status = status_code,
+                path = %path,
+                response_body = %body,
+                "Board update failed"
+            );
+            return Err(PlankaError::Status(status_code, body));
+        }
+
+        let data: BoardCreateResponse = resp.json().await.map_err(|e| {
+            error!(error = %e, path = %path, "Failed to parse board update response");
+            e
+        })?;
+
+        self.cache.invalidate_board(board_id);
+        if let Some(project_id) = data.item.project_id.as_deref() {
+            self.cache.invalidate_project(project_id);
+        }
+        self.cache.invalidate_allowed_ids();
+        info!(board_id = %board_id, "Board updated successfully");
+        trace!(board = ?data.item, "Updated board details");
+        Ok(data.item)
+    }
+
+    /// Resolves a placement to a position among the other boards of the project
+    async fn board_position(&self, board_id: &str, placement: &Placement) -> Result<f64, PlankaError> {
+        if let Placement::At(position) = placement {
+            return Ok(*position);
+        }
+        let board = self.fetch_board(board_id, false).await?;
+        let project_id = board.item.project_id.ok_or_else(|| {
+            PlankaError::Invalid(format!("board {board_id} has no project"))
+        })?;
+        let project = self.fetch_project(&project_id, true).await?;
+        let siblings: Vec<(&str, Option<f64>)> = project
+            .included
+            .boards
+            .iter()
+            .map(|b| (b.id.as_str(), b.position))
+            .collect();
+        let slot = position_for(&siblings, placement, Some(board_id))?;
+        if !slot.renumber.is_empty() {
+            let renumbered = self.renumber_positions("boards", &slot.renumber).await;
+            for (sibling_id, _) in &slot.renumber {
+                self.cache.invalidate_board(sibling_id);
+            }
+            self.cache.invalidate_project(&project_id);
+            renumbered?;
+        }
+        debug!(board_id = %board_id, placement = ?placement, position = slot.position, "Computed board position");
+        Ok(slot.position)
+    }
+
+    pub async fn delete_board(&self, board_id: &str, confirm: &str) -> Result<(), PlankaError> {
+        warn!(board_id = %board_id, "Deleting board and all its lists and cards");
+        self.ensure_board_in_scope(board_id).await?;
+        let board = self.fetch_board(board_id, true).await?;
+        if board.item.name != confirm {
+            warn!(board_id = %board_id, "Board deletion not confirmed");
+            return Err(PlankaError::Invalid(format!(
+                "confirm must be the board name {:?} to delete board {board_id}",
+                board.item.name
+            )));
+        }
+        self.delete_board_in_scope(board_id).await
+    }
+
+    /// Deletes a board whose scope the caller has checked, without asking for
+    /// confirmation; used to roll back boards created moments before
+    pub(super) async fn delete_board_in_scope(&self, board_id: &str) -> Result<(), PlankaError> {
+        let board = self.fetch_board(board_id, false).await?;
+        let path = format!("/api/boards/{board_id}");
+
+        let resp = self.request(reqwest::Method::DELETE, &path)
+            .await?
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, path = %path, "Failed to send board deletion request");
+                e
+            })?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let body = resp.text().await.unwrap_or_default();
+            error!(
+                status = status_code,
+                path = %path,
+                response_body = %body,
+                "Board deletion failed"
+            );
+            return Err(PlankaError::Status(status_code, body));
+        }
+
+        self.cache.invalidate_board(board_id);
+        if let Some(project_id) = board.item.project_id.as_deref() {
+            self.cache.invalidate_project(project_id);
+        }
+        self.cache.invalidate_allowed_ids();
+        info!(board_id = %board_id, "Board deleted successfully");
+        Ok(())
+    }
+
+    /// Lists the members of a board with their role
+    pub async fn list_board_members(&self, board_id: &str) -> Result<Vec<BoardMember>, PlankaError> {
+        debug!(board_id = %board_id, "Listing board members");
+        self.ensure_board_in_scope(board_id).await?;
+        let board = self.fetch_board(board_id, true).await?;
+
+        let members: Vec<BoardMember> = board
+            .included
+            .board_memberships
+            .iter()
+            .map(|m| {
+                let user = board.included.users.iter().find(|u| u.id == m.user_id);
+                BoardMember {
+                    membership_id: m.id.clone(),
+                    user_id: m.user_id.clone(),
+                    name: user.map(|u| u.name.clone()),
+                    username: user.and_then(|u| u.username.clone()),
+                    role: m.role.clone(),
+                    can_comment: m.can_comment,
+                }
+            })
+            .collect();
+
+        info!(board_id = %board_id, count = members.len(), "Successfully listed board members");
+        Ok(members)
+    }
+
+    async fn find_board_membership(&self, board_id: &str, user_id: &str) -> Result<BoardMembership, PlankaError> {
+        let board = self.fetch_board(board_id, true).await?;
+        board
+            .included
+            .board_memberships
+            .into_iter()
+            .find(|m| m.user_id == user_id)
+            .ok_or_else(|| PlankaError::Invalid(format!("user {user_id} is not a member of board {board_id}")))
+    }
+
+    pub async fn add_board_member(
+        &self,
+        board_id: &str,
+        user_id: &str,
+        role: &str,
+        can_comment: Option<bool>,
+    ) -> Result<BoardMembership, PlankaError> {
+        info!(board_id = %board_id, user_id = %user_id, role = %role, "Adding board member");
+        self.ensure_board_in_scope(board_id).await?;
+        let path = format!("/api/boards/{board_id}/board-memberships");
+
+        let mut body = serde_json::Map::new();
+        body.insert("userId".to_string(), serde_json::Value::String(user_id.to_string()));
+        body.insert("role".to_string(), serde_json::Value::String(role.to_string()));
+        if let Some(c) = can_comment {
+            body.insert("canComment".to_string(), serde_json::Value::Bool(c));
+        }
+
+        let data = self.send_board_membership(reqwest::Method::POST, &path, Some(&body)).await?;
+        self.cache.invalidate_board(board_id);
+        self.cache.invalidate_allowed_ids();
+        info!(membership_id = %data.id, "Board member added successfully");
+        Ok(data)
+    }
+
+    pub async fn update_board_member(
+        &self,
+        board_id: &str,
+        user_id: &str,
+        role: Option<&str>,
+        can_comment: Option<bool>,
+    ) -> Result<BoardMembership, PlankaError> {
+        info!(board_id = %board_id, user_id = %user_id, role = ?role, "Updating board member");
+        self.ensure_board_in_scope(board_id).await?;
+        let membership = self.find_board_membership(board_id, user_id).await?;
+        let path = format!("/api/board-memberships/{}", membership.id);
+
+        let mut body = serde_json::Map::new();
+        if let Some(r) = role {
+            body.insert("role".to_string(), serde_json::Value::String(r.to_string()));
+        }
+        if let Some(c) = can_comment {
+            body.insert("canComment".to_string(), serde_json::Value::Bool(c));
+        }
+        if body.is_empty() {
+            return Err(PlankaError::Invalid("nothing to update".into()));
+        }
+
+        let data = self.send_board_membership(reqwest::Method::PATCH, &path, Some(&body)).await?;
+        self.cache.invalidate_board(board_id);
+        info!(membership_id = %data.id, "Board member updated successfully");
+        Ok(data)
+    }
+
+    pub async fn remove_board_member(&self, board_id: &str, user_id: &str) -> Result<(), PlankaError> {
+        warn!(board_id = %board_id, user_id = %user_id, "Removing board member");
+        self.ensure_board_in_scope(board_id).await?;
+        let membership = self.find_board_membership(board_id, user_id).await?;
+        let path = format!("/api/board-memberships/{}", membership.id);
+
+        self.send_board_membership(reqwest::Method::DELETE, &path, None).await?;
+        self.cache.invalidate_board(board_id);
+        self.cache.invalidate_allowed_ids();
+        info!(board_id = %board_id, user_id = %user_id, "Board member removed successfully");
+        Ok(())
+    }
+
+    async fn send_board_membership(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&serde_json::Map<String, serde_json::Value>>,
+    ) -> Result<BoardMembership, PlankaError> {
+        trace!(method = %method, request_body = ?body, "Board membership request");
+        let mut request = self.request(method, path).await?;
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+        let resp = request
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, path = %path, "Failed to send board membership request");
+                e
+            })?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let body = resp.text().await.unwrap_or_default();
+            error!(
+                status = status_code,
+                path = %path,
+                response_body = %body,
+                "Board membership request failed"
+            );
+            return Err(PlankaError::Status(status_code, body));
+        }
+
+        let data: BoardMembershipResponse = resp.json().await.map_err(|e| {
+            error!(error = %e, path = %path, "Failed to parse board membership response");
+            e
+        })?;
+        Ok(data.item)
+    }
+
+    pub async fn create_list(
+        &self,
+        board_id: &str,
+        name: &str,
+    ) -> Result<List, PlankaError> {
+        info!(board_id = %board_id, name = %name, "Creating new list");
+        self.ensure_board_in_scope(board_id).await?;
+        let position = self.list_position(board_id, &Placement::Bottom, None).await?;
+        let path = format!("/api/boards/{board_id}/lists");
+
+        let body = CreateListRequest {
+            name: name.to_string(),
+            position,
+        };
+
+        trace!(request_body = ?body, "List creation request");
 
         let resp = self.request(reqwest::Method::POST, &path)
             .await?
@@ -486,11 +1595,179 @@ impl PlankaClient {
             e
         })?;
         
+        self.cache.invalidate_board(board_id);
         info!(list_id = %data.item.id, "List created successfully");
         trace!(list = ?data.item, "Created list details");
         Ok(data.item)
     }
 
+    /// Resolves a placement to a position among the board's current lists.
+    /// Archive and trash lists have no position and are ignored.
+    async fn list_position(
+        &self,
+        board_id: &str,
+        placement: &Placement,
+        moving: Option<&str>,
+    ) -> Result<f64, PlankaError> {
+        if let Placement::At(position) = placement {
+            return Ok(*position);
+        }
+        let board = self.fetch_board(board_id, true).await?;
+        let siblings: Vec<(&str, Option<f64>)> = board
+            .included
+            .lists
+            .iter()
+            .filter(|l| l.position.is_some())
+            .map(|l| (l.id.as_str(), l.position))
+            .collect();
+        let slot = position_for(&siblings, placement, moving)?;
+        if !slot.renumber.is_empty() {
+            let renumbered = self.renumber_positions("lists", &slot.renumber).await;
+            self.cache.invalidate_board(board_id);
+            renumbered?;
+        }
+        debug!(board_id = %board_id, placement = ?placement, position = slot.position, "Computed list position");
+        Ok(slot.position)
+    }
+
+    pub async fn update_list(&self, list_id: &str, options: UpdateListOptions) -> Result<List, PlankaError> {
+        info!(list_id = %list_id, "Updating list");
+        trace!(options = ?options, "List update options");
+        self.ensure_list_in_scope(list_id).await?;
+
+        let mut body = serde_json::Map::new();
+        if let Some(n) = options.name {
+            body.insert("name".to_string(), serde_json::Value::String(n));
+        }
+        if let Some(placement) = options.placement {
+            let list = self.fetch_list(list_id).await?;
+            let pos = self.list_position(&list.item.board_id, &placement, Some(list_id)).await?;
+            let position = serde_json::Number::from_f64(pos)
+                .ok_or_else(|| PlankaError::Invalid(format!("position must be a finite number, got {pos}")))?;
+            body.insert("position".to_string(), serde_json::Value::Number(position));
+        }
+        if body.is_empty() {
+            return Err(PlankaError::Invalid("nothing to update".into()));
+        }
+
+        let data = self.patch_list(list_id, &body).await?;
+        self.cache.invalidate_board(&data.board_id);
+        info!(list_id = %list_id, "List updated successfully");
+        trace!(list = ?data, "Updated list details");
+        Ok(data)
+    }
+
+    /// Puts all lists of a board into the given order, patching only the
+    /// lists whose position changes
+    pub async fn reorder_lists(&self, board_id: &str, order: &[String]) -> Result<Vec<List>, PlankaError> {
+        info!(board_id = %board_id, count = order.len(), "Reordering lists");
+        self.ensure_board_in_scope(board_id).await?;
+        let board = self.fetch_board(board_id, true).await?;
+        let siblings: Vec<(&str, Option<f64>)> = board
+            .included
+            .lists
+            .iter()
+            .filter(|l| l.position.is_some())
+            .map(|l| (l.id.as_str(), l.position))
+            .collect();
+        let plan = reorder(&siblings, order)?;
+        debug!(board_id = %board_id, changes = plan.len(), "Planned list reordering");
+
+        let mut moved: Vec<&str> = Vec::new();
+        for (list_id, pos) in &plan {
+            if let Err(e) = self.patch_list_position(list_id, *pos).await {
+                // Earlier updates went through, so the cached order is stale
+                self.cache.invalidate_board(board_id);
+                return Err(self.restore_list_positions(&siblings, &moved, e).await);
+            }
+            moved.push(list_id);
+        }
+        self.cache.invalidate_board(board_id);
+
+        let mut lists = self.fetch_board(board_id, true).await?.included.lists;
+        lists.retain(|l| l.position.is_some());
+        lists.sort_by(|a, b| a.position.unwrap_or(0.0).total_cmp(&b.position.unwrap_or(0.0)));
+        info!(board_id = %board_id, updated = plan.len(), "Lists reordered successfully");
+        Ok(lists)
+    }
+
+    async fn patch_list_position(&self, list_id: &str, pos: f64) -> Result<List, PlankaError> {
+        let position = serde_json::Number::from_f64(pos)
+            .ok_or_else(|| PlankaError::Invalid(format!("position must be a finite number, got {pos}")))?;
+        let mut body = serde_json::Map::new();
+        body.insert("position".to_string(), serde_json::Value::Number(position));
+        self.patch_list(list_id, &body).await
+    }
+
+    /// Writes back the recorded positions of the lists a failed reordering
+    /// already moved. Returns the error to report, naming any list that
+    /// could not be moved back.
+    async fn restore_list_positions(
+        &self,
+        siblings: &[(&str, Option<f64>)],
+        moved: &[&str],
+        error: PlankaError,
+    ) -> PlankaError {
+        warn!(moved = moved.len(), error = %error, "Reordering lists failed, restoring previous positions");
+        let mut stuck = Vec::new();
+        for list_id in moved {
+            let original = siblings.iter().find(|(id, _)| id == list_id).and_then(|(_, p)| *p);
+            let restored = match original {
+                Some(pos) => self.patch_list_position(list_id, pos).await.map(|_| ()),
+                None => Ok(()),
+            };
+            if let Err(e) = restored {
+                warn!(list_id = %list_id, error = %e, "Failed to restore list position");
+                stuck.push(*list_id);
+            }
+        }
+        if stuck.is_empty() {
+            return error;
+        }
+        PlankaError::Invalid(format!(
+            "{error}; lists {} keep their new position as restoring them failed",
+            stuck.join(", ")
+        ))
+    }
+
+    async fn patch_list(
+        &self,
+        list_id: &str,
+        body: &serde_json::Map<String, serde_json::Value>,
+    ) -> Result<List, PlankaError> {
+        let path = format!("/api/lists/{list_id}");
+        trace!(request_body = ?body, "List update request");
+
+        let resp = self.request(reqwest::Method::PATCH, &path)
+            .await?
+            .json(body)
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, path = %path, "Failed to send list update request");
+                e
+            })?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let body = resp.text().await.unwrap_or_default();
+            error!(
+                status = status_code,
+                path = %path,
+                response_body = %body,
+                "List update failed"
+            );
+            return Err(PlankaError::Status(status_code, body));
+        }
+
+        let data: ListResponse = resp.json().await.map_err(|e| {
+            error!(error = %e, path = %path, "Failed to parse list update response");
+            e
+        })?;
+        Ok(data.item)
+    }
+
     pub async fn update_card(
         &self,
         card_id: &str,
@@ -498,7 +1775,19 @@ impl PlankaClient {
     ) -> Result<Card, PlankaError> {
         info!(card_id = %card_id, "Updating card");
         trace!(options = ?options, "Card update options");
-        
+        self.ensure_card_in_scope(card_id).await?;
+        if let Some(board_id) = options.board_id.as_deref() {
+            self.ensure_board_in_scope(board_id).await?;
+        }
+        self.update_card_in_scope(card_id, options).await
+    }
+
+    /// Updates a card whose scope, and that of any target board, the caller has checked
+    pub(super) async fn update_card_in_scope(
+        &self,
+        card_id: &str,
+        options: UpdateCardOptions,
+    ) -> Result<Card, PlankaError> {
         let path = format!("/api/cards/{card_id}");
 
         let mut body = serde_json::Map::new();
@@ -554,6 +1843,8 @@ impl PlankaClient {
             e
         })?;
         
+        self.cache.invalidate_card(card_id);
+        self.cache.invalidate_list(&data.item.list_id);
         info!(card_id = %card_id, "Card updated successfully");
         trace!(card = ?data.item, "Updated card details");
         Ok(data.item)
@@ -563,15 +1854,29 @@ impl PlankaClient {
         &self,
         card_id: &str,
         list_id: &str,
-        position: Option<f64>,
+        placement: Placement,
     ) -> Result<Card, PlankaError> {
-        info!(card_id = %card_id, list_id = %list_id, position = ?position, "Moving card");
+        info!(card_id = %card_id, list_id = %list_id, placement = ?placement, "Moving card");
+        self.ensure_card_in_scope(card_id).await?;
+        self.ensure_list_in_scope(list_id).await?;
+        self.move_card_in_scope(card_id, list_id, placement).await
+    }
+
+    /// Moves a card whose scope, and that of the target list, the caller has checked
+    pub(super) async fn move_card_in_scope(
+        &self,
+        card_id: &str,
+        list_id: &str,
+        placement: Placement,
+    ) -> Result<Card, PlankaError> {
+        let pos = self.card_position(list_id, &placement, Some(card_id)).await?;
         let path = format!("/api/cards/{card_id}");
 
         let mut body = serde_json::Map::new();
         body.insert("listId".to_string(), serde_json::Value::String(list_id.to_string()));
-        let pos = position.unwrap_or(65535.0);
-        body.insert("position".to_string(), serde_json::Value::Number(serde_json::Number::from_f64(pos).unwrap()));
+        let position = serde_json::Number::from_f64(pos)
+            .ok_or_else(|| PlankaError::Invalid(format!("position must be a finite number, got {pos}")))?;
+        body.insert("position".to_string(), serde_json::Value::Number(position));
 
         trace!(request_body = ?body, "Card move request");
 
@@ -603,13 +1908,235 @@ impl PlankaClient {
             e
         })?;
         
+        self.cache.invalidate_card(card_id);
+        self.cache.invalidate_list(list_id);
         info!(card_id = %card_id, new_list_id = %list_id, "Card moved successfully");
         trace!(card = ?data.item, "Moved card details");
         Ok(data.item)
     }
 
+    /// Creates a child item with a POST request and returns the created item.
+    /// Callers are responsible for scope checks and cache invalidation.
+    pub(super) async fn post_item<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &serde_json::Value,
+    ) -> Result<T, PlankaError> {
+        trace!(path = %path, request_body = ?body, "Item creation request");
+
+        let resp = self.request(reqwest::Method::POST, path)
+            .await?
+            .json(body)
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, path = %path, "Failed to send item creation request");
+                e
+            })?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let body = resp.text().await.unwrap_or_default();
+            error!(
+                status = status_code,
+                path = %path,
+                response_body = %body,
+                "Item creation failed"
+            );
+            return Err(PlankaError::Status(status_code, body));
+        }
+
+        let data: ItemResponse<T> = resp.json().await.map_err(|e| {
+            error!(error = %e, path = %path, "Failed to parse item creation response");
+            e
+        })?;
+        Ok(data.item)
+    }
+
+    /// Creates a label on a board whose scope the caller has checked
+    pub(super) async fn create_label(
+        &self,
+        board_id: &str,
+        name: Option<&str>,
+        color: &str,
+        position: f64,
+    ) -> Result<Label, PlankaError> {
+        debug!(board_id = %board_id, name = ?name, color = %color, "Creating label");
+        let path = format!("/api/boards/{board_id}/labels");
+        let body = serde_json::json!({ "name": name, "color": color, "position": position });
+        let label: Label = self.post_item(&path, &body).await?;
+        self.cache.invalidate_board(board_id);
+        Ok(label)
+    }
+
+    /// Updates the name and color of a label on a board whose scope the caller has checked
+    pub(super) async fn update_label(
+        &self,
+        label_id: &str,
+        name: Option<&str>,
+        color: &str,
+    ) -> Result<Label, PlankaError> {
+        debug!(label_id = %label_id, name = ?name, color = %color, "Updating label");
+        let path = format!("/api/labels/{label_id}");
+        let body = serde_json::json!({ "name": name, "color": color });
+
+        let resp = self.request(reqwest::Method::PATCH, &path)
+            .await?
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, path = %path, "Failed to send label update request");
+                e
+            })?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let body = resp.text().await.unwrap_or_default();
+            error!(
+                status = status_code,
+                path = %path,
+                response_body = %body,
+                "Label update failed"
+            );
+            return Err(PlankaError::Status(status_code, body));
+        }
+
+        let data: ItemResponse<Label> = resp.json().await.map_err(|e| {
+            error!(error = %e, path = %path, "Failed to parse label update response");
+            e
+        })?;
+        self.cache.invalidate_board(&data.item.board_id);
+        Ok(data.item)
+    }
+
+    /// Deletes a label on a board whose scope the caller has checked
+    pub(super) async fn delete_label(&self, board_id: &str, label_id: &str) -> Result<(), PlankaError> {
+        warn!(board_id = %board_id, label_id = %label_id, "Deleting label");
+        let path = format!("/api/labels/{label_id}");
+
+        let resp = self.request(reqwest::Method::DELETE, &path)
+            .await?
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, path = %path, "Failed to send label deletion request");
+                e
+            })?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let status_code = status.as_u16();
+            let body = resp.text().await.unwrap_or_default();
+            error!(
+                status = status_code,
+                path = %path,
+                response_body = %body,
+                "Label deletion failed"
+            );
+            return Err(PlankaError::Status(status_code, body));
+        }
+
+        self.cache.invalidate_board(board_id);
+        Ok(())
+    }
+
+    /// Creates a list at an exact position on a board whose scope the caller has checked
+    pub(super) async fn create_list_at(&self, board_id: &str, name: &str, position: f64) -> Result<List, PlankaError> {
+        debug!(board_id = %board_id, name = %name, position, "Creating list");
+        let path = format!("/api/boards/{board_id}/lists");
+        let body = CreateListRequest {
+            name: name.to_string(),
+            position,
+        };
+        let list: List = self.post_item(&path, &serde_json::to_value(&body)?).await?;
+        self.cache.invalidate_board(board_id);
+        Ok(list)
+    }
+
+    /// Creates a board at an exact position in a project whose scope the caller has checked
+    pub(super) async fn create_board_at(&self, project_id: &str, name: &str, position: f64) -> Result<Board, PlankaError> {
+        debug!(project_id = %project_id, name = %name, position, "Creating board");
+        let path = format!("/api/projects/{project_id}/boards");
+        let body = CreateBoardRequest {
+            name: name.to_string(),
+            position,
+        };
+        let board: Board = self.post_item(&path, &serde_json::to_value(&body)?).await?;
+        self.cache.invalidate_project(project_id);
+        self.cache.invalidate_allowed_ids();
+        Ok(board)
+    }
+
+    /// Adds a comment to a card whose scope the caller has checked
+    pub(super) async fn create_comment(&self, card_id: &str, text: &str) -> Result<Comment, PlankaError> {
+        debug!(card_id = %card_id, "Creating comment");
+        let path = format!("/api/cards/{card_id}/comments");
+        let body = serde_json::json!({ "text": text });
+        self.post_item(&path, &body).await
+    }
+
+    /// Attaches a label to a card whose scope the caller has checked
+    pub(super) async fn add_card_label(&self, card_id: &str, label_id: &str) -> Result<CardLabel, PlankaError> {
+        debug!(card_id = %card_id, label_id = %label_id, "Adding label to card");
+        let path = format!("/api/cards/{card_id}/card-labels");
+        let body = serde_json::json!({ "labelId": label_id });
+        let card_label = self.post_item(&path, &body).await?;
+        self.cache.invalidate_card(card_id);
+        Ok(card_label)
+    }
+
+    /// Adds a user to a card whose scope the caller has checked
+    pub(super) async fn add_card_member(&self, card_id: &str, user_id: &str) -> Result<CardMembership, PlankaError> {
+        debug!(card_id = %card_id, user_id = %user_id, "Adding member to card");
+        let path = format!("/api/cards/{card_id}/card-memberships");
+        let body = serde_json::json!({ "userId": user_id });
+        let membership = self.post_item(&path, &body).await?;
+        self.cache.invalidate_card(card_id);
+        Ok(membership)
+    }
+
+    /// Creates a task list on a card whose scope the caller has checked
+    pub(super) async fn create_task_list(
+        &self,
+        card_id: &str,
+        name: &str,
+        position: f64,
+    ) -> Result<TaskList, PlankaError> {
+        debug!(card_id = %card_id, name = %name, "Creating task list");
+        let path = format!("/api/cards/{card_id}/task-lists");
+        let body = serde_json::json!({ "name": name, "position": position });
+        let task_list = self.post_item(&path, &body).await?;
+        self.cache.invalidate_card(card_id);
+        Ok(task_list)
+    }
+
+    /// Creates a task in a task list whose scope the caller has checked
+    pub(super) async fn create_task(
+        &self,
+        task_list_id: &str,
+        name: &str,
+        position: f64,
+        is_completed: bool,
+    ) -> Result<Task, PlankaError> {
+        debug!(task_list_id = %task_list_id, name = %name, "Creating task");
+        let path = format!("/api/task-lists/{task_list_id}/tasks");
+        let body = serde_json::json!({ "name": name, "position": position, "isCompleted": is_completed });
+        let task = self.post_item(&path, &body).await?;
+        self.cache.invalidate_task_list(task_list_id);
+        Ok(task)
+    }
+
     pub async fn delete_card(&self, card_id: &str) -> Result<(), PlankaError> {
         warn!(card_id = %card_id, "Deleting card");
+        self.ensure_card_in_scope(card_id).await?;
+        self.delete_card_in_scope(card_id).await
+    }
+
+    /// Deletes a card whose scope the caller has checked
+    pub(super) async fn delete_card_in_scope(&self, card_id: &str) -> Result<(), PlankaError> {
         let path = format!("/api/cards/{card_id}");
 
         let resp = self.request(reqwest::Method::DELETE, &path)
@@ -634,12 +2161,14 @@ impl PlankaClient {
             return Err(PlankaError::Status(status_code, body));
         }
 
+        self.cache.invalidate_card(card_id);
         info!(card_id = %card_id, "Card deleted successfully");
         Ok(())
     }
 
     pub async fn delete_list(&self, list_id: &str) -> Result<(), PlankaError> {
         warn!(list_id = %list_id, "Deleting list and all its cards");
+        self.ensure_list_in_scope(list_id).await?;
         let path = format!("/api/lists/{list_id}");
 
         let resp = self.request(reqwest::Method::DELETE, &path)
@@ -664,11 +2193,80 @@ impl PlankaClient {
             return Err(PlankaError::Status(status_code, body));
         }
 
+        self.cache.invalidate_list(list_id);
         info!(list_id = %list_id, "List deleted successfully");
         Ok(())
     }
 }
 
+/// Reads a secret from a file, ignoring surrounding whitespace
+fn read_secret_file(path: &Path) -> Result<String, PlankaError> {
+    let secret = std::fs::read_to_string(path).map_err(|e| {
+        error!(path = %path.display(), error = %e, "Failed to read secret file");
+        PlankaError::Config(format!("Cannot read secret file {}: {e}", path.display()))
+    })?;
+    let secret = secret.trim();
+    if secret.is_empty() {
+        error!(path = %path.display(), "Secret file is empty");
+        return Err(PlankaError::Config(format!("Secret file {} is empty", path.display())));
+    }
+    Ok(secret.to_string())
+}
+
+/// Saves an access token for reuse by the next run, readable by the owner only
+fn persist_token(path: &Path, token: &str) {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let result = options
+        .open(path)
+        .and_then(|mut file| std::io::Write::write_all(&mut file, token.as_bytes()));
+    match result {
+        Ok(()) => debug!(path = %path.display(), "Persisted access token"),
+        Err(e) => warn!(path = %path.display(), error = %e, "Failed to persist access token"),
+    }
+}
+
+/// Runs a credential helper through the shell and returns its trimmed output
+async fn run_token_command(command: &str) -> Result<String, PlankaError> {
+    info!("Fetching token from credential helper");
+    let output = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::null())
+        .stderr(Stdio::inherit())
+        .output()
+        .await
+        .map_err(|e| {
+            error!(error = %e, "Failed to run credential helper");
+            PlankaError::Config(format!("Cannot run credential helper: {e}"))
+        })?;
+
+    if !output.status.success() {
+        error!(status = %output.status, "Credential helper failed");
+        return Err(PlankaError::Config(format!("Credential helper exited with {}", output.status)));
+    }
+
+    let token = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if token.is_empty() {
+        error!("Credential helper printed no token");
+        return Err(PlankaError::Config("Credential helper printed no token".into()));
+    }
+    Ok(token)
+}
+
+impl From<reqwest::Error> for PlankaError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            PlankaError::Timeout(e)
+        } else {
+            PlankaError::Http(e)
+        }
+    }
+}
+
 impl From<url::ParseError> for PlankaError {
     fn from(e: url::ParseError) -> Self {
         PlankaError::Config(format!("URL parse error: {e}"))
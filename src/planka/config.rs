@@ -0,0 +1,199 @@
+use serde::Deserialize;
+use std::path::PathBuf;
+
+use super::client::PlankaError;
+
+/// Connection settings for a single Planka server.
+///
+/// Every field is optional so that a profile from the config file can be
+/// layered with environment variable overrides before the client is built.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientConfig {
+    pub url: Option<String>,
+    pub token: Option<String>,
+    /// File holding the token, re-read on every request (e.g. a Docker secret)
+    pub token_file: Option<PathBuf>,
+    /// Shell command whose standard output is the token
+    pub token_command: Option<String>,
+    /// How long the output of `token_command` is reused, in seconds
+    pub token_command_ttl_secs: Option<u64>,
+    pub email: Option<String>,
+    pub password: Option<String>,
+    /// File holding the password (e.g. a Docker secret)
+    pub password_file: Option<PathBuf>,
+    /// File where the access token obtained with email/password is kept
+    /// between runs instead of being revoked on shutdown
+    pub token_cache_file: Option<PathBuf>,
+    pub disable_ssl: Option<bool>,
+    /// PEM file or directory of PEM files with additional root certificates
+    pub ca_cert: Option<PathBuf>,
+    /// PEM client certificate chain for mutual TLS (may also hold the key)
+    pub client_cert: Option<PathBuf>,
+    /// PEM private key for `client_cert`
+    pub client_key: Option<PathBuf>,
+    /// SHA-256 fingerprints of certificates the server must present
+    #[serde(default)]
+    pub pinned_certs: Vec<String>,
+    /// Total request timeout in seconds
+    pub timeout_secs: Option<u64>,
+    /// Timeout for establishing a connection, in seconds
+    pub connect_timeout_secs: Option<u64>,
+    /// Timeout for each read from the connection, in seconds
+    pub read_timeout_secs: Option<u64>,
+    /// Proxy for all requests (`http://`, `https://`, `socks5://` or `socks5h://`)
+    pub proxy: Option<String>,
+    /// Hosts that bypass the proxy
+    #[serde(default)]
+    pub no_proxy: Vec<String>,
+    /// Maximum idle connections kept per host
+    pub pool_max_idle_per_host: Option<usize>,
+    /// How long idle connections are kept, in seconds
+    pub pool_idle_timeout_secs: Option<u64>,
+    /// TCP keepalive interval, in seconds
+    pub tcp_keepalive_secs: Option<u64>,
+    /// How long board and project snapshots are reused, in seconds (0 disables)
+    pub cache_ttl_secs: Option<u64>,
+    #[serde(default)]
+    pub projects: Vec<String>,
+    #[serde(default)]
+    pub boards: Vec<String>,
+}
+
+impl ClientConfig {
+    /// Builds a configuration from environment variables only
+    pub fn from_env() -> Result<Self, PlankaError> {
+        let mut config = Self::default();
+        config.apply_env()?;
+        Ok(config)
+    }
+
+    /// Overrides fields with any of `PLANKA_URL`, `PLANKA_TOKEN`, `PLANKA_TOKEN_FILE`,
+    /// `PLANKA_TOKEN_COMMAND`, `PLANKA_TOKEN_COMMAND_TTL`, `PLANKA_EMAIL`,
+    /// `PLANKA_PASSWORD`, `PLANKA_PASSWORD_FILE`, `PLANKA_TOKEN_CACHE`, `DISABLE_SSL`, `PLANKA_CA_CERT`, `PLANKA_CLIENT_CERT`,
+    /// `PLANKA_CLIENT_KEY`, `PLANKA_PINNED_CERTS`, `PLANKA_TIMEOUT`,
+    /// `PLANKA_CONNECT_TIMEOUT`, `PLANKA_READ_TIMEOUT`, `PLANKA_PROXY`,
+    /// `PLANKA_NO_PROXY`, `PLANKA_POOL_MAX_IDLE`, `PLANKA_POOL_IDLE_TIMEOUT`,
+    /// `PLANKA_TCP_KEEPALIVE`, `PLANKA_CACHE_TTL`, `PLANKA_PROJECTS` and
+    /// `PLANKA_BOARDS` that are set. Fails on a numeric variable that does not parse.
+    pub fn apply_env(&mut self) -> Result<(), PlankaError> {
+        if let Ok(v) = std::env::var("PLANKA_URL") {
+            self.url = Some(v);
+        }
+        if let Ok(v) = std::env::var("PLANKA_TOKEN") {
+            self.token = Some(v);
+        }
+        if let Ok(v) = std::env::var("PLANKA_TOKEN_FILE") {
+            self.token_file = Some(v.into());
+        }
+        if let Ok(v) = std::env::var("PLANKA_TOKEN_COMMAND") {
+            self.token_command = Some(v);
+        }
+        if let Some(secs) = env_number("PLANKA_TOKEN_COMMAND_TTL")? {
+            self.token_command_ttl_secs = Some(secs);
+        }
+        if let Ok(v) = std::env::var("PLANKA_EMAIL") {
+            self.email = Some(v);
+        }
+        if let Ok(v) = std::env::var("PLANKA_PASSWORD") {
+            self.password = Some(v);
+        }
+        if let Ok(v) = std::env::var("PLANKA_PASSWORD_FILE") {
+            self.password_file = Some(v.into());
+        }
+        if let Ok(v) = std::env::var("PLANKA_TOKEN_CACHE") {
+            self.token_cache_file = Some(v.into());
+        }
+        if let Ok(v) = std::env::var("DISABLE_SSL") {
+            self.disable_ssl = Some(parse_bool(&v));
+        }
+        if let Ok(v) = std::env::var("PLANKA_CA_CERT") {
+            self.ca_cert = Some(v.into());
+        }
+        if let Ok(v) = std::env::var("PLANKA_CLIENT_CERT") {
+            self.client_cert = Some(v.into());
+        }
+        if let Ok(v) = std::env::var("PLANKA_CLIENT_KEY") {
+            self.client_key = Some(v.into());
+        }
+        if let Ok(v) = std::env::var("PLANKA_PINNED_CERTS") {
+            self.pinned_certs = parse_list(&v);
+        }
+        if let Some(secs) = env_number("PLANKA_TIMEOUT")? {
+            self.timeout_secs = Some(secs);
+        }
+        if let Some(secs) = env_number("PLANKA_CONNECT_TIMEOUT")? {
+            self.connect_timeout_secs = Some(secs);
+        }
+        if let Some(secs) = env_number("PLANKA_READ_TIMEOUT")? {
+            self.read_timeout_secs = Some(secs);
+        }
+        if let Ok(v) = std::env::var("PLANKA_PROXY") {
+            self.proxy = Some(v);
+        }
+        if let Ok(v) = std::env::var("PLANKA_NO_PROXY") {
+            self.no_proxy = parse_list(&v);
+        }
+        if let Some(max) = env_number("PLANKA_POOL_MAX_IDLE")? {
+            self.pool_max_idle_per_host = Some(max);
+        }
+        if let Some(secs) = env_number("PLANKA_POOL_IDLE_TIMEOUT")? {
+            self.pool_idle_timeout_secs = Some(secs);
+        }
+        if let Some(secs) = env_number("PLANKA_TCP_KEEPALIVE")? {
+            self.tcp_keepalive_secs = Some(secs);
+        }
+        if let Some(secs) = env_number("PLANKA_CACHE_TTL")? {
+            self.cache_ttl_secs = Some(secs);
+        }
+        if let Ok(v) = std::env::var("PLANKA_PROJECTS") {
+            self.projects = parse_list(&v);
+        }
+        if let Ok(v) = std::env::var("PLANKA_BOARDS") {
+            self.boards = parse_list(&v);
+        }
+        Ok(())
+    }
+}
+
+/// Reads a numeric variable; `None` when it is unset
+fn env_number<T: std::str::FromStr>(key: &str) -> Result<Option<T>, PlankaError> {
+    match std::env::var(key) {
+        Ok(v) => parse_number(key, &v).map(Some),
+        Err(_) => Ok(None),
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(key: &str, value: &str) -> Result<T, PlankaError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| PlankaError::Config(format!("{key} must be a whole number, got {value:?}")))
+}
+
+/// Accepts various boolean representations (case-insensitive)
+pub fn parse_bool(value: &str) -> bool {
+    matches!(value.trim().to_lowercase().as_str(), "true" | "1" | "yes" | "on")
+}
+
+/// Splits a comma-separated list, dropping blank entries
+pub fn parse_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_number_names_bad_values() {
+        assert_eq!(parse_number::<u64>("PLANKA_TIMEOUT", " 30 ").unwrap(), 30);
+        let err = parse_number::<u64>("PLANKA_TIMEOUT", "30s").unwrap_err();
+        assert_eq!(err.to_string(), "Configuration error: PLANKA_TIMEOUT must be a whole number, got \"30s\"");
+    }
+}
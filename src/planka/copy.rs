@@ -0,0 +1,395 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, info, warn};
+
+use super::client::{PlankaClient, PlankaError};
+use super::position::{Placement, POSITION_GAP};
+use super::types::{Board, BoardIncluded, BoardResponse, Card, CreateCardOptions, Label, List};
+
+/// Parts of a card copied by [`PlankaClient::duplicate_card`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CardPart {
+    Description,
+    DueDate,
+    Labels,
+    TaskLists,
+    Members,
+}
+
+impl CardPart {
+    pub const ALL: [CardPart; 5] = [
+        CardPart::Description,
+        CardPart::DueDate,
+        CardPart::Labels,
+        CardPart::TaskLists,
+        CardPart::Members,
+    ];
+}
+
+/// Options for duplicating a card
+#[derive(Debug, Clone)]
+pub struct DuplicateCardOptions {
+    /// List receiving the copy, on the same or another board
+    pub list_id: String,
+    /// Name of the copy; defaults to the source card's name
+    pub name: Option<String>,
+    pub placement: Placement,
+    pub parts: Vec<CardPart>,
+}
+
+/// What was copied onto the new card
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateCardReport {
+    pub card: Card,
+    pub labels: usize,
+    /// Labels missing on the target board that were created there
+    pub created_labels: Vec<Label>,
+    pub task_lists: usize,
+    pub tasks: usize,
+    pub members: usize,
+    /// Parts that could not be copied, with the reason
+    pub skipped: Vec<String>,
+}
+
+/// Options for cloning a board into a project
+#[derive(Debug, Clone)]
+pub struct CloneBoardOptions {
+    pub project_id: String,
+    pub name: String,
+    /// Recreate the board's labels
+    pub labels: bool,
+    /// Recreate the cards with their description, due date, labels and task lists
+    pub cards: bool,
+}
+
+/// What was created by [`PlankaClient::clone_board`]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloneBoardReport {
+    pub board: Board,
+    pub lists: Vec<List>,
+    pub labels: Vec<Label>,
+    pub cards: usize,
+    pub task_lists: usize,
+    pub tasks: usize,
+}
+
+/// Finds the label on the target board corresponding to a source label:
+/// same name (case-insensitive), or same color for unnamed labels
+pub fn matching_label<'a>(labels: &'a [Label], source: &Label) -> Option<&'a Label> {
+    labels.iter().find(|l| match (&l.name, &source.name) {
+        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+        (None, None) => l.color == source.color,
+        _ => false,
+    })
+}
+
+impl PlankaClient {
+    /// Copies a card into a list, together with the selected parts. Labels are
+    /// remapped by name when the target list is on another board. If copying
+    /// any part fails, the new card is deleted again.
+    pub async fn duplicate_card(
+        &self,
+        card_id: &str,
+        options: DuplicateCardOptions,
+    ) -> Result<DuplicateCardReport, PlankaError> {
+        info!(card_id = %card_id, list_id = %options.list_id, parts = ?options.parts, "Duplicating card");
+        self.ensure_card_in_scope(card_id).await?;
+        let source = self.fetch_card(card_id).await?;
+        let source_board_id = match source.board_id.clone() {
+            Some(id) => id,
+            None => self.fetch_list(&source.list_id).await?.item.board_id,
+        };
+        let source_board = self.fetch_board(&source_board_id, true).await?;
+        let target_board_id = self.fetch_list(&options.list_id).await?.item.board_id;
+        let target_board = if target_board_id == source_board_id {
+            source_board.clone()
+        } else {
+            self.fetch_board(&target_board_id, true).await?
+        };
+
+        let copies = |part| options.parts.contains(&part);
+        let card = self
+            .create_card(CreateCardOptions {
+                list_id: options.list_id.clone(),
+                placement: options.placement.clone(),
+                card_type: source.card_type.clone(),
+                name: options.name.clone().unwrap_or_else(|| source.name.clone()),
+                description: source.description.clone().filter(|_| copies(CardPart::Description)),
+                due_date: source.due_date.clone().filter(|_| copies(CardPart::DueDate)),
+                is_due_completed: source.is_due_completed.filter(|_| copies(CardPart::DueDate)),
+                stopwatch: None,
+            })
+            .await?;
+
+        let mut report = DuplicateCardReport {
+            card,
+            labels: 0,
+            created_labels: Vec::new(),
+            task_lists: 0,
+            tasks: 0,
+            members: 0,
+            skipped: Vec::new(),
+        };
+        let result = self
+            .copy_card_parts(&source, &source_board, &target_board, &options.parts, &mut report)
+            .await;
+        if let Err(e) = result {
+            warn!(card_id = %report.card.id, error = %e, "Copying card contents failed, deleting the partial copy");
+            if let Err(cleanup) = self.delete_card(&report.card.id).await {
+                warn!(card_id = %report.card.id, error = %cleanup, "Failed to delete partial card copy");
+            }
+            for label in &report.created_labels {
+                if let Err(cleanup) = self.delete_label(&label.board_id, &label.id).await {
+                    warn!(label_id = %label.id, error = %cleanup, "Failed to delete label created for the copy");
+                }
+            }
+            return Err(e);
+        }
+
+        info!(
+            card_id = %card_id,
+            new_card_id = %report.card.id,
+            labels = report.labels,
+            task_lists = report.task_lists,
+            members = report.members,
+            skipped = report.skipped.len(),
+            "Card duplicated successfully"
+        );
+        Ok(report)
+    }
+
+    async fn copy_card_parts(
+        &self,
+        source: &Card,
+        source_board: &BoardResponse,
+        target_board: &BoardResponse,
+        parts: &[CardPart],
+        report: &mut DuplicateCardReport,
+    ) -> Result<(), PlankaError> {
+        let new_card_id = report.card.id.clone();
+        let same_board = source_board.item.id == target_board.item.id;
+        let src = &source_board.included;
+
+        if parts.contains(&CardPart::Labels) {
+            let mut target_labels = target_board.included.labels.clone();
+            for card_label in src.card_labels.iter().filter(|l| l.card_id == source.id) {
+                let Some(label) = src.labels.iter().find(|l| l.id == card_label.label_id) else {
+                    debug!(label_id = %card_label.label_id, "Source label not found on board");
+                    continue;
+                };
+                let label_id = if same_board {
+                    label.id.clone()
+                } else if let Some(existing) = matching_label(&target_labels, label) {
+                    existing.id.clone()
+                } else {
+                    let position = target_labels
+                        .iter()
+                        .filter_map(|l| l.position)
+                        .fold(0.0, f64::max)
+                        + POSITION_GAP;
+                    let created = self
+                        .create_label(&target_board.item.id, label.name.as_deref(), &label.color, position)
+                        .await?;
+                    let id = created.id.clone();
+                    report.created_labels.push(created.clone());
+                    target_labels.push(created);
+                    id
+                };
+                self.add_card_label(&new_card_id, &label_id).await?;
+                report.labels += 1;
+            }
+        }
+
+        if parts.contains(&CardPart::TaskLists) {
+            let (task_lists, tasks) = self.copy_task_lists(src, &source.id, &new_card_id).await?;
+            report.task_lists += task_lists;
+            report.tasks += tasks;
+        }
+
+        if parts.contains(&CardPart::Members) {
+            for membership in src.card_memberships.iter().filter(|m| m.card_id == source.id) {
+                let allowed = same_board
+                    || target_board
+                        .included
+                        .board_memberships
+                        .iter()
+                        .any(|m| m.user_id == membership.user_id);
+                if !allowed {
+                    report
+                        .skipped
+                        .push(format!("member {}: not a member of the target board", membership.user_id));
+                    continue;
+                }
+                self.add_card_member(&new_card_id, &membership.user_id).await?;
+                report.members += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Recreates the task lists and tasks of a card on another card, in
+    /// position order. Returns the number of task lists and tasks created.
+    pub(super) async fn copy_task_lists(
+        &self,
+        src: &BoardIncluded,
+        source_card_id: &str,
+        new_card_id: &str,
+    ) -> Result<(usize, usize), PlankaError> {
+        let mut task_lists: Vec<_> = src.task_lists.iter().filter(|t| t.card_id == source_card_id).collect();
+        task_lists.sort_by(|a, b| a.position.unwrap_or(0.0).total_cmp(&b.position.unwrap_or(0.0)));
+        let (mut list_count, mut task_count) = (0, 0);
+        for (i, task_list) in task_lists.into_iter().enumerate() {
+            let position = task_list.position.unwrap_or((i + 1) as f64 * POSITION_GAP);
+            let created = self.create_task_list(new_card_id, &task_list.name, position).await?;
+            list_count += 1;
+
+            let mut tasks: Vec<_> = src.tasks.iter().filter(|t| t.task_list_id == task_list.id).collect();
+            tasks.sort_by(|a, b| a.position.unwrap_or(0.0).total_cmp(&b.position.unwrap_or(0.0)));
+            for (j, task) in tasks.into_iter().enumerate() {
+                let position = task.position.unwrap_or((j + 1) as f64 * POSITION_GAP);
+                self.create_task(&created.id, &task.name, position, task.is_completed).await?;
+                task_count += 1;
+            }
+        }
+        Ok((list_count, task_count))
+    }
+
+    /// Creates a new board in a project with the lists of an existing board,
+    /// and optionally its labels and cards. Archive and trash lists are not
+    /// copied. If any step fails, the new board is deleted again.
+    pub async fn clone_board(
+        &self,
+        board_id: &str,
+        options: CloneBoardOptions,
+    ) -> Result<CloneBoardReport, PlankaError> {
+        info!(
+            board_id = %board_id,
+            project_id = %options.project_id,
+            name = %options.name,
+            labels = options.labels,
+            cards = options.cards,
+            "Cloning board"
+        );
+        self.ensure_board_in_scope(board_id).await?;
+        let source = self.fetch_board(board_id, true).await?;
+        let board = self.create_board(&options.project_id, &options.name).await?;
+
+        let mut report = CloneBoardReport {
+            board,
+            lists: Vec::new(),
+            labels: Vec::new(),
+            cards: 0,
+            task_lists: 0,
+            tasks: 0,
+        };
+        if let Err(e) = self.copy_board_contents(&source.included, &options, &mut report).await {
+            warn!(board_id = %report.board.id, error = %e, "Cloning board contents failed, deleting the partial board");
+            if let Err(cleanup) = self.delete_board_in_scope(&report.board.id).await {
+                warn!(board_id = %report.board.id, error = %cleanup, "Failed to delete partial board clone");
+            }
+            return Err(e);
+        }
+
+        info!(
+            board_id = %board_id,
+            new_board_id = %report.board.id,
+            lists = report.lists.len(),
+            labels = report.labels.len(),
+            cards = report.cards,
+            "Board cloned successfully"
+        );
+        Ok(report)
+    }
+
+    async fn copy_board_contents(
+        &self,
+        src: &BoardIncluded,
+        options: &CloneBoardOptions,
+        report: &mut CloneBoardReport,
+    ) -> Result<(), PlankaError> {
+        let new_board_id = report.board.id.clone();
+
+        let mut lists: Vec<&List> = src.lists.iter().filter(|l| l.position.is_some()).collect();
+        lists.sort_by(|a, b| a.position.unwrap_or(0.0).total_cmp(&b.position.unwrap_or(0.0)));
+        let mut list_ids = HashMap::new();
+        for list in lists {
+            let position = list.position.unwrap_or(POSITION_GAP);
+            let created = self.create_list_at(&new_board_id, &list.name, position).await?;
+            debug!(source_list_id = %list.id, list_id = %created.id, "Cloned list");
+            list_ids.insert(list.id.as_str(), created.id.clone());
+            report.lists.push(created);
+        }
+
+        let mut label_ids = HashMap::new();
+        if options.labels {
+            for label in &src.labels {
+                let position = label.position.unwrap_or((report.labels.len() + 1) as f64 * POSITION_GAP);
+                let created = self
+                    .create_label(&new_board_id, label.name.as_deref(), &label.color, position)
+                    .await?;
+                label_ids.insert(label.id.as_str(), created.id.clone());
+                report.labels.push(created);
+            }
+        }
+
+        if options.cards {
+            for card in &src.cards {
+                let Some(list_id) = list_ids.get(card.list_id.as_str()) else {
+                    debug!(card_id = %card.id, "Skipping card outside the cloned lists");
+                    continue;
+                };
+                let created = self
+                    .create_card(CreateCardOptions {
+                        list_id: list_id.clone(),
+                        placement: Placement::At(card.position.unwrap_or(POSITION_GAP)),
+                        card_type: card.card_type.clone(),
+                        name: card.name.clone(),
+                        description: card.description.clone(),
+                        due_date: card.due_date.clone(),
+                        is_due_completed: card.is_due_completed,
+                        stopwatch: None,
+                    })
+                    .await?;
+                report.cards += 1;
+
+                for card_label in src.card_labels.iter().filter(|l| l.card_id == card.id) {
+                    if let Some(label_id) = label_ids.get(card_label.label_id.as_str()) {
+                        self.add_card_label(&created.id, label_id).await?;
+                    }
+                }
+                let (task_lists, tasks) = self.copy_task_lists(src, &card.id, &created.id).await?;
+                report.task_lists += task_lists;
+                report.tasks += tasks;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label(id: &str, name: Option<&str>, color: &str) -> Label {
+        Label {
+            id: id.to_string(),
+            board_id: "1".to_string(),
+            name: name.map(str::to_string),
+            color: color.to_string(),
+            position: None,
+        }
+    }
+
+    #[test]
+    fn test_matching_label_by_name_or_color() {
+        let labels = [label("a", Some("Bug"), "berry-red"), label("b", None, "sky-blue")];
+        let found = |l: &Label| matching_label(&labels, l).map(|l| l.id.as_str());
+        assert_eq!(found(&label("x", Some("bug"), "pink-tulip")), Some("a"));
+        assert_eq!(found(&label("x", None, "sky-blue")), Some("b"));
+        assert_eq!(found(&label("x", None, "berry-red")), None);
+        assert_eq!(found(&label("x", Some("Feature"), "sky-blue")), None);
+    }
+}
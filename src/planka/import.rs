@@ -0,0 +1,660 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use tracing::{debug, info, warn};
+
+use super::client::{PlankaClient, PlankaError};
+use super::position::{Placement, POSITION_GAP};
+use super::types::{CardType, CreateCardOptions};
+
+/// Color of labels created for sources that do not define one
+const DEFAULT_LABEL_COLOR: &str = "morning-sky";
+
+/// Source formats understood by [`parse`]
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportFormat {
+    Csv,
+    /// `#` headings as lists and top-level bullets as cards
+    Markdown,
+    /// A board exported from Trello as JSON
+    Trello,
+}
+
+/// A card to import, independent of the source format
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImportCard {
+    /// Stable id used to skip cards imported by an earlier run
+    pub external_id: String,
+    pub list: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub labels: Vec<String>,
+    pub due_date: Option<String>,
+    pub is_due_completed: Option<bool>,
+    /// Checklists as names with their tasks and completion
+    pub task_lists: Vec<(String, Vec<(String, bool)>)>,
+}
+
+/// Cards parsed from a source, with the colors of the labels it defines
+#[derive(Debug, Clone, Default)]
+pub struct ImportBatch {
+    pub cards: Vec<ImportCard>,
+    pub label_colors: HashMap<String, String>,
+}
+
+/// Header names of the CSV columns. Unset columns are found by their usual
+/// names, e.g. `name` or `title` for the card name.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CsvColumns {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub list: Option<String>,
+    pub labels: Option<String>,
+    pub due_date: Option<String>,
+}
+
+/// What an import created, or would create in a dry run
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub lists_created: Vec<String>,
+    pub labels_created: Vec<String>,
+    pub cards_created: Vec<String>,
+    /// Cards whose external id was imported before
+    pub cards_skipped: usize,
+    pub tasks_created: usize,
+}
+
+/// Parses cards from a source document. Cards without a list go to `default_list`.
+pub fn parse(
+    format: ImportFormat,
+    content: &str,
+    columns: &CsvColumns,
+    default_list: &str,
+) -> Result<ImportBatch, PlankaError> {
+    match format {
+        ImportFormat::Csv => parse_csv(content, columns, default_list),
+        ImportFormat::Markdown => Ok(parse_markdown(content, default_list)),
+        ImportFormat::Trello => parse_trello(content),
+    }
+}
+
+/// Id for cards whose source has none, derived from their list and name
+fn derived_id(list: &str, name: &str) -> String {
+    let digest = Sha256::digest(format!("{}\n{name}", list.to_lowercase()).as_bytes());
+    let hex: String = digest.iter().take(8).map(|b| format!("{b:02x}")).collect();
+    format!("h:{hex}")
+}
+
+/// Name given to Trello labels that have neither a name nor a color
+const UNNAMED_LABEL: &str = "Unnamed label";
+
+/// Marker appended to the description of imported cards
+fn import_marker(external_id: &str) -> String {
+    format!("<!-- import-id: {external_id} -->")
+}
+
+/// Reads the external id back from a card description
+fn imported_id(description: &str) -> Option<&str> {
+    let start = description.rfind("<!-- import-id: ")? + "<!-- import-id: ".len();
+    let end = description[start..].find(" -->")?;
+    Some(&description[start..start + end])
+}
+
+/// Splits CSV text into records, handling quoted fields with embedded
+/// commas, quotes and line breaks. Blank lines are dropped.
+fn csv_records(text: &str) -> Result<Vec<Vec<String>>, PlankaError> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(c) = chars.next() {
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => quoted = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => quoted = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(PlankaError::Invalid("CSV has an unterminated quoted field".into()));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records.retain(|r| r.iter().any(|f| !f.trim().is_empty()));
+    Ok(records)
+}
+
+/// Accepts plain `YYYY-MM-DD` dates in addition to the ISO timestamps Planka expects
+fn normalize_due_date(value: &str) -> String {
+    if value.len() == 10 && value.as_bytes()[4] == b'-' && value.as_bytes()[7] == b'-' {
+        format!("{value}T00:00:00.000Z")
+    } else {
+        value.to_string()
+    }
+}
+
+fn parse_csv(content: &str, columns: &CsvColumns, default_list: &str) -> Result<ImportBatch, PlankaError> {
+    let mut records = csv_records(content)?.into_iter();
+    let header = records
+        .next()
+        .ok_or_else(|| PlankaError::Invalid("CSV has no header row".into()))?;
+    let column = |configured: Option<&str>, usual: &[&str]| -> Result<Option<usize>, PlankaError> {
+        match configured {
+            Some(name) => header
+                .iter()
+                .position(|h| h.trim().eq_ignore_ascii_case(name))
+                .map(Some)
+                .ok_or_else(|| PlankaError::Invalid(format!("CSV has no column {name:?}"))),
+            None => Ok(header
+                .iter()
+                .position(|h| usual.iter().any(|u| h.trim().eq_ignore_ascii_case(u)))),
+        }
+    };
+    let name_column = column(columns.name.as_deref(), &["name", "title"])?
+        .ok_or_else(|| PlankaError::Invalid("CSV needs a name or title column".into()))?;
+    let id_column = column(columns.id.as_deref(), &["id"])?;
+    let description_column = column(columns.description.as_deref(), &["description"])?;
+    let list_column = column(columns.list.as_deref(), &["list"])?;
+    let labels_column = column(columns.labels.as_deref(), &["labels"])?;
+    let due_column = column(columns.due_date.as_deref(), &["due_date", "due date", "due"])?;
+
+    let mut cards = Vec::new();
+    for (row, record) in records.enumerate() {
+        let cell = |index: Option<usize>| {
+            index
+                .and_then(|i| record.get(i))
+                .map(|v| v.trim())
+                .filter(|v| !v.is_empty())
+        };
+        let Some(name) = cell(Some(name_column)) else {
+            debug!(row = row + 2, "Skipping CSV row without a name");
+            continue;
+        };
+        let list = cell(list_column).unwrap_or(default_list);
+        cards.push(ImportCard {
+            external_id: match cell(id_column) {
+                Some(id) => format!("csv:{id}"),
+                None => derived_id(list, name),
+            },
+            list: list.to_string(),
+            name: name.to_string(),
+            description: cell(description_column).map(str::to_string),
+            labels: cell(labels_column)
+                .map(|l| {
+                    l.split([',', ';'])
+                        .map(str::trim)
+                        .filter(|l| !l.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            due_date: cell(due_column).map(normalize_due_date),
+            ..Default::default()
+        });
+    }
+    Ok(ImportBatch {
+        cards,
+        label_colors: HashMap::new(),
+    })
+}
+
+fn parse_markdown(content: &str, default_list: &str) -> ImportBatch {
+    let mut cards: Vec<ImportCard> = Vec::new();
+    let mut list = default_list.to_string();
+    let mut in_card = false;
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        if let Some(heading) = trimmed.strip_prefix('#').filter(|_| indent == 0) {
+            list = heading.trim_start_matches('#').trim().to_string();
+            in_card = false;
+            continue;
+        }
+        let bullet = ["- ", "* ", "+ "].iter().find_map(|b| trimmed.strip_prefix(b));
+        match (bullet, cards.last_mut()) {
+            (Some(item), _) if indent < 2 => {
+                let name = ["[ ] ", "[x] ", "[X] "]
+                    .iter()
+                    .find_map(|c| item.strip_prefix(c))
+                    .unwrap_or(item)
+                    .trim();
+                in_card = !name.is_empty();
+                if in_card {
+                    cards.push(ImportCard {
+                        external_id: derived_id(&list, name),
+                        list: list.clone(),
+                        name: name.to_string(),
+                        ..Default::default()
+                    });
+                }
+            }
+            (_, Some(card)) if in_card && indent >= 2 => {
+                let text = line.get(2..).unwrap_or(trimmed);
+                let description = card.description.get_or_insert_with(String::new);
+                if !description.is_empty() {
+                    description.push('\n');
+                }
+                description.push_str(text);
+            }
+            _ => in_card = in_card && trimmed.is_empty(),
+        }
+    }
+    ImportBatch {
+        cards,
+        label_colors: HashMap::new(),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrelloBoard {
+    #[serde(default)]
+    lists: Vec<TrelloList>,
+    #[serde(default)]
+    cards: Vec<TrelloCard>,
+    #[serde(default)]
+    labels: Vec<TrelloLabel>,
+    #[serde(default)]
+    checklists: Vec<TrelloChecklist>,
+}
+
+#[derive(Deserialize)]
+struct TrelloList {
+    id: String,
+    name: String,
+    #[serde(default)]
+    closed: bool,
+    #[serde(default)]
+    pos: f64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrelloCard {
+    id: String,
+    name: String,
+    #[serde(default)]
+    desc: String,
+    id_list: String,
+    #[serde(default)]
+    id_labels: Vec<String>,
+    #[serde(default)]
+    due: Option<String>,
+    #[serde(default)]
+    due_complete: bool,
+    #[serde(default)]
+    closed: bool,
+    #[serde(default)]
+    pos: f64,
+}
+
+#[derive(Deserialize)]
+struct TrelloLabel {
+    id: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    color: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TrelloChecklist {
+    id_card: String,
+    name: String,
+    #[serde(default)]
+    pos: f64,
+    #[serde(default)]
+    check_items: Vec<TrelloCheckItem>,
+}
+
+#[derive(Deserialize)]
+struct TrelloCheckItem {
+    name: String,
+    state: String,
+    #[serde(default)]
+    pos: f64,
+}
+
+/// Closest Planka label color for a Trello color
+fn planka_color(trello: Option<&str>) -> &'static str {
+    match trello {
+        Some("green") => "bright-moss",
+        Some("yellow") => "egg-yellow",
+        Some("orange") => "pumpkin-orange",
+        Some("red") => "berry-red",
+        Some("purple") => "red-burgundy",
+        Some("blue") => "lagoon-blue",
+        Some("sky") => "morning-sky",
+        Some("lime") => "sunny-grass",
+        Some("pink") => "pink-tulip",
+        Some("black") => "dark-granite",
+        _ => DEFAULT_LABEL_COLOR,
+    }
+}
+
+/// Reads open lists and cards from a Trello JSON export, in board order
+fn parse_trello(content: &str) -> Result<ImportBatch, PlankaError> {
+    let board: TrelloBoard = serde_json::from_str(content)
+        .map_err(|e| PlankaError::Invalid(format!("invalid Trello export: {e}")))?;
+
+    let mut unnamed = 0;
+    let label_names: HashMap<&str, String> = board
+        .labels
+        .iter()
+        .map(|l| {
+            let name = match (l.name.is_empty(), l.color.as_deref()) {
+                (false, _) => l.name.clone(),
+                (true, Some(color)) if !color.is_empty() => color.to_string(),
+                (true, _) => {
+                    unnamed += 1;
+                    match unnamed {
+                        1 => UNNAMED_LABEL.to_string(),
+                        n => format!("{UNNAMED_LABEL} {n}"),
+                    }
+                }
+            };
+            (l.id.as_str(), name)
+        })
+        .collect();
+    let label_colors = board
+        .labels
+        .iter()
+        .map(|l| (label_names[l.id.as_str()].clone(), planka_color(l.color.as_deref()).to_string()))
+        .collect();
+
+    let mut lists: Vec<&TrelloList> = board.lists.iter().filter(|l| !l.closed).collect();
+    lists.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+    let mut cards = Vec::new();
+    for list in lists {
+        let mut list_cards: Vec<&TrelloCard> = board
+            .cards
+            .iter()
+            .filter(|c| c.id_list == list.id && !c.closed)
+            .collect();
+        list_cards.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+        for card in list_cards {
+            let mut checklists: Vec<&TrelloChecklist> = board.checklists.iter().filter(|c| c.id_card == card.id).collect();
+            checklists.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+            let task_lists = checklists
+                .into_iter()
+                .map(|checklist| {
+                    let mut items: Vec<&TrelloCheckItem> = checklist.check_items.iter().collect();
+                    items.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+                    let tasks = items.into_iter().map(|i| (i.name.clone(), i.state == "complete")).collect();
+                    (checklist.name.clone(), tasks)
+                })
+                .collect();
+            cards.push(ImportCard {
+                external_id: format!("trello:{}", card.id),
+                list: list.name.clone(),
+                name: card.name.clone(),
+                description: Some(card.desc.clone()).filter(|d| !d.is_empty()),
+                labels: card
+                    .id_labels
+                    .iter()
+                    .filter_map(|id| label_names.get(id.as_str()).cloned())
+                    .collect(),
+                due_date: card.due.clone(),
+                is_due_completed: card.due.as_ref().map(|_| card.due_complete),
+                task_lists,
+            });
+        }
+    }
+    Ok(ImportBatch { cards, label_colors })
+}
+
+impl PlankaClient {
+    /// Creates the cards of a batch on a board, together with missing lists
+    /// and labels. Each card carries its external id in a marker at the end
+    /// of its description, so cards imported by an earlier run are skipped.
+    pub async fn import_cards(
+        &self,
+        board_id: &str,
+        batch: ImportBatch,
+        dry_run: bool,
+    ) -> Result<ImportReport, PlankaError> {
+        info!(board_id = %board_id, cards = batch.cards.len(), dry_run, "Importing cards");
+        self.ensure_board_in_scope(board_id).await?;
+        let board = self.fetch_board(board_id, true).await?;
+        let included = &board.included;
+
+        // Only cards from earlier runs count: rows of this batch may share an id
+        let imported: HashSet<String> = included
+            .cards
+            .iter()
+            .filter_map(|c| imported_id(c.description.as_deref()?))
+            .map(str::to_string)
+            .collect();
+        let mut lists: HashMap<String, String> = included
+            .lists
+            .iter()
+            .filter(|l| l.position.is_some())
+            .map(|l| (l.name.to_lowercase(), l.id.clone()))
+            .collect();
+        let mut next_list_position = included.lists.iter().filter_map(|l| l.position).fold(0.0, f64::max);
+        let mut card_positions: HashMap<String, f64> = HashMap::new();
+        for card in &included.cards {
+            let last = card_positions.entry(card.list_id.clone()).or_insert(0.0);
+            *last = last.max(card.position.unwrap_or(0.0));
+        }
+        let mut labels: HashMap<String, String> = included
+            .labels
+            .iter()
+            .filter_map(|l| Some((l.name.as_deref()?.to_lowercase(), l.id.clone())))
+            .collect();
+        let mut next_label_position = included.labels.iter().filter_map(|l| l.position).fold(0.0, f64::max);
+
+        let mut report = ImportReport {
+            dry_run,
+            ..Default::default()
+        };
+        for card in batch.cards {
+            if imported.contains(&card.external_id) {
+                debug!(external_id = %card.external_id, "Skipping already imported card");
+                report.cards_skipped += 1;
+                continue;
+            }
+
+            let list_id = match lists.get(&card.list.to_lowercase()) {
+                Some(id) => id.clone(),
+                None => {
+                    next_list_position += POSITION_GAP;
+                    let id = if dry_run {
+                        String::new()
+                    } else {
+                        self.create_list_at(board_id, &card.list, next_list_position).await?.id
+                    };
+                    report.lists_created.push(card.list.clone());
+                    lists.insert(card.list.to_lowercase(), id.clone());
+                    id
+                }
+            };
+            let mut label_ids = Vec::with_capacity(card.labels.len());
+            for name in &card.labels {
+                let id = match labels.get(&name.to_lowercase()) {
+                    Some(id) => id.clone(),
+                    None => {
+                        next_label_position += POSITION_GAP;
+                        let color = batch.label_colors.get(name).map_or(DEFAULT_LABEL_COLOR, String::as_str);
+                        let id = if dry_run {
+                            String::new()
+                        } else {
+                            self.create_label(board_id, Some(name), color, next_label_position).await?.id
+                        };
+                        report.labels_created.push(name.clone());
+                        labels.insert(name.to_lowercase(), id.clone());
+                        id
+                    }
+                };
+                label_ids.push(id);
+            }
+
+            let tasks: usize = card.task_lists.iter().map(|(_, t)| t.len()).sum();
+            if dry_run {
+                report.cards_created.push(card.name);
+                report.tasks_created += tasks;
+                continue;
+            }
+
+            let position = card_positions.entry(list_id.clone()).or_insert(0.0);
+            *position += POSITION_GAP;
+            let marker = import_marker(&card.external_id);
+            let created = self
+                .create_card(CreateCardOptions {
+                    list_id,
+                    placement: Placement::At(*position),
+                    card_type: CardType::Project,
+                    name: card.name.clone(),
+                    description: Some(match &card.description {
+                        Some(description) => format!("{description}\n\n{marker}"),
+                        None => marker,
+                    }),
+                    due_date: card.due_date.clone(),
+                    is_due_completed: card.is_due_completed,
+                    stopwatch: None,
+                })
+                .await?;
+            if let Err(e) = self.fill_imported_card(&created.id, &label_ids, &card).await {
+                warn!(card_id = %created.id, error = %e, "Importing card contents failed, deleting the partial card");
+                if let Err(cleanup) = self.delete_card(&created.id).await {
+                    warn!(card_id = %created.id, error = %cleanup, "Failed to delete partially imported card");
+                }
+                return Err(e);
+            }
+            report.cards_created.push(card.name);
+            report.tasks_created += tasks;
+        }
+
+        info!(
+            board_id = %board_id,
+            created = report.cards_created.len(),
+            skipped = report.cards_skipped,
+            lists = report.lists_created.len(),
+            labels = report.labels_created.len(),
+            "Cards imported successfully"
+        );
+        Ok(report)
+    }
+
+    async fn fill_imported_card(&self, card_id: &str, label_ids: &[String], card: &ImportCard) -> Result<(), PlankaError> {
+        for label_id in label_ids {
+            self.add_card_label(card_id, label_id).await?;
+        }
+        for (i, (name, tasks)) in card.task_lists.iter().enumerate() {
+            let task_list = self.create_task_list(card_id, name, (i + 1) as f64 * POSITION_GAP).await?;
+            for (j, (task, completed)) in tasks.iter().enumerate() {
+                self.create_task(&task_list.id, task, (j + 1) as f64 * POSITION_GAP, *completed).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_with_quotes_and_column_mapping() {
+        let content = "Key,Title,Notes,Status,Tags,Due\n\
+                       7,\"Fix, \"\"quickly\"\"\",\"two\nlines\",Doing,bug; ui,2026-03-01\n\
+                       ,Write docs,,,,\n";
+        let columns = CsvColumns {
+            id: Some("key".into()),
+            description: Some("Notes".into()),
+            list: Some("Status".into()),
+            labels: Some("Tags".into()),
+            ..Default::default()
+        };
+        let batch = parse(ImportFormat::Csv, content, &columns, "Inbox").unwrap();
+        assert_eq!(batch.cards.len(), 2);
+        let first = &batch.cards[0];
+        assert_eq!(first.external_id, "csv:7");
+        assert_eq!(first.name, "Fix, \"quickly\"");
+        assert_eq!(first.description.as_deref(), Some("two\nlines"));
+        assert_eq!(first.labels, vec!["bug", "ui"]);
+        assert_eq!(first.due_date.as_deref(), Some("2026-03-01T00:00:00.000Z"));
+        assert_eq!(batch.cards[1].list, "Inbox");
+        assert_eq!(batch.cards[1].external_id, derived_id("inbox", "Write docs"));
+
+        let missing = CsvColumns {
+            list: Some("Column".into()),
+            ..Default::default()
+        };
+        assert!(parse(ImportFormat::Csv, content, &missing, "Inbox").is_err());
+    }
+
+    #[test]
+    fn test_markdown_headings_and_bullets() {
+        let content = "- Loose item\n\n# Todo\n\n- [ ] Write spec\n  with details\n  - sub point\n- Review\n\nSome paragraph\n";
+        let batch = parse(ImportFormat::Markdown, content, &CsvColumns::default(), "Inbox").unwrap();
+        let cards: Vec<(&str, &str, Option<&str>)> = batch
+            .cards
+            .iter()
+            .map(|c| (c.list.as_str(), c.name.as_str(), c.description.as_deref()))
+            .collect();
+        assert_eq!(
+            cards,
+            vec![
+                ("Inbox", "Loose item", None),
+                ("Todo", "Write spec", Some("with details\n- sub point")),
+                ("Todo", "Review", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_trello_export_skips_closed_items() {
+        let content = r#"{
+            "lists": [{"id": "l2", "name": "Done", "pos": 2}, {"id": "l1", "name": "Todo", "pos": 1},
+                      {"id": "l3", "name": "Old", "closed": true}],
+            "labels": [{"id": "g", "name": "", "color": "green"}, {"id": "n1", "name": ""},
+                       {"id": "n2", "name": "", "color": null}],
+            "cards": [
+                {"id": "c1", "name": "Ship", "idList": "l2", "idLabels": ["g", "n1", "n2"], "due": "2026-01-01T00:00:00.000Z", "dueComplete": true},
+                {"id": "c2", "name": "Plan", "idList": "l1", "desc": "Notes"},
+                {"id": "c3", "name": "Archived", "idList": "l1", "closed": true},
+                {"id": "c4", "name": "Stale", "idList": "l3"}
+            ],
+            "checklists": [{"idCard": "c2", "name": "Steps", "checkItems": [
+                {"name": "b", "state": "incomplete", "pos": 2}, {"name": "a", "state": "complete", "pos": 1}]}]
+        }"#;
+        let batch = parse(ImportFormat::Trello, content, &CsvColumns::default(), "Inbox").unwrap();
+        let names: Vec<&str> = batch.cards.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Plan", "Ship"]);
+        assert_eq!(batch.cards[0].task_lists, vec![("Steps".to_string(), vec![("a".to_string(), true), ("b".to_string(), false)])]);
+        assert_eq!(batch.cards[1].labels, vec!["green", "Unnamed label", "Unnamed label 2"]);
+        assert_eq!(batch.cards[1].is_due_completed, Some(true));
+        assert_eq!(batch.label_colors["green"], "bright-moss");
+    }
+
+    #[test]
+    fn test_import_marker_round_trip() {
+        let description = format!("Notes\n\n{}", import_marker("trello:c1"));
+        assert_eq!(imported_id(&description), Some("trello:c1"));
+        assert_eq!(imported_id("Notes"), None);
+    }
+}
@@ -1,4 +1,19 @@
+pub mod backup;
+pub mod bulk;
+pub mod cache;
 pub mod client;
+pub mod config;
+pub mod copy;
+pub mod import;
+pub mod position;
+pub mod registry;
+pub mod scope;
+pub mod spec;
+#[cfg(test)]
+pub(crate) mod test_support;
+pub mod tls;
 pub mod types;
+pub mod undo;
 
 pub use client::PlankaClient;
+pub use registry::ClientRegistry;
@@ -0,0 +1,186 @@
+use super::client::PlankaError;
+
+/// Distance Planka itself leaves between consecutive items
+pub const POSITION_GAP: f64 = 65536.0;
+
+/// Where to place a card or list among its siblings
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Placement {
+    Top,
+    #[default]
+    Bottom,
+    Before(String),
+    After(String),
+    /// Raw position value, used as given
+    At(f64),
+}
+
+/// Smallest distance kept between neighbours; a placement that would leave
+/// less room renumbers the siblings instead
+pub const MIN_POSITION_GAP: f64 = 1.0;
+
+/// Where to put an item, and the siblings that must be moved first to make room
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slot {
+    pub position: f64,
+    /// Siblings with their new positions, empty when the gap was wide enough
+    pub renumber: Vec<(String, f64)>,
+}
+
+/// Computes the position for an item placed among `siblings` (id and
+/// position pairs in any order). The item being moved, if given, is ignored,
+/// so moving a card next to itself keeps its position rather than failing.
+pub fn position_for(
+    siblings: &[(&str, Option<f64>)],
+    placement: &Placement,
+    moving: Option<&str>,
+) -> Result<Slot, PlankaError> {
+    let unchanged = |position: f64| Slot { position, renumber: Vec::new() };
+    if let Placement::At(position) = placement {
+        return Ok(unchanged(*position));
+    }
+    if let (Placement::Before(target) | Placement::After(target), Some(moving)) = (placement, moving) {
+        if target == moving {
+            if let Some((_, Some(current))) = siblings.iter().find(|(id, _)| *id == moving) {
+                return Ok(unchanged(*current));
+            }
+        }
+    }
+
+    let mut siblings: Vec<(&str, f64)> = siblings
+        .iter()
+        .filter(|(id, _)| Some(*id) != moving)
+        .map(|(id, position)| (*id, position.unwrap_or(0.0)))
+        .collect();
+    siblings.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    let index_of = |target: &str| {
+        siblings
+            .iter()
+            .position(|(id, _)| *id == target)
+            .ok_or_else(|| PlankaError::Invalid(format!("{target} is not in the target list")))
+    };
+    let index = match placement {
+        Placement::Top => 0,
+        Placement::Bottom => siblings.len(),
+        Placement::Before(target) => index_of(target)?,
+        Placement::After(target) => index_of(target)? + 1,
+        Placement::At(_) => unreachable!("handled above"),
+    };
+
+    // Positions start above 0, so the top slot lies between 0 and the first item
+    let previous = index.checked_sub(1).map_or(0.0, |i| siblings[i].1);
+    match siblings.get(index) {
+        None => Ok(unchanged(previous + POSITION_GAP)),
+        Some((_, next)) if next - previous >= 2.0 * MIN_POSITION_GAP => Ok(unchanged((previous + next) / 2.0)),
+        Some(_) => {
+            let slot = |i: usize| (i + 1) as f64 * POSITION_GAP;
+            let renumber = siblings
+                .iter()
+                .enumerate()
+                .map(|(i, (id, current))| (*id, *current, slot(if i < index { i } else { i + 1 })))
+                .filter(|(_, current, target)| current != target)
+                .map(|(id, _, target)| (id.to_string(), target))
+                .collect();
+            Ok(Slot { position: slot(index), renumber })
+        }
+    }
+}
+
+/// Plans the position updates that put `siblings` into `order`, which must
+/// name every sibling exactly once. Returns only the items whose position
+/// changes, with their new evenly spaced positions.
+pub fn reorder(
+    siblings: &[(&str, Option<f64>)],
+    order: &[String],
+) -> Result<Vec<(String, f64)>, PlankaError> {
+    let unknown: Vec<&str> = order
+        .iter()
+        .map(String::as_str)
+        .filter(|id| !siblings.iter().any(|(s, _)| s == id))
+        .collect();
+    let missing: Vec<&str> = siblings
+        .iter()
+        .map(|(id, _)| *id)
+        .filter(|id| !order.iter().any(|o| o == id))
+        .collect();
+    if !unknown.is_empty() || !missing.is_empty() || order.len() != siblings.len() {
+        return Err(PlankaError::Invalid(format!(
+            "order must list every item exactly once (unknown: [{}], missing: [{}])",
+            unknown.join(", "),
+            missing.join(", ")
+        )));
+    }
+
+    Ok(order
+        .iter()
+        .enumerate()
+        .map(|(i, id)| (id, (i + 1) as f64 * POSITION_GAP))
+        .filter(|(id, target)| {
+            siblings
+                .iter()
+                .any(|(s, current)| s == id && *current != Some(*target))
+        })
+        .map(|(id, target)| (id.clone(), target))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIBLINGS: &[(&str, Option<f64>)] = &[("b", Some(200.0)), ("a", Some(100.0)), ("c", Some(300.0))];
+
+    #[test]
+    fn test_position_for_placements() {
+        let at = |p: Placement| position_for(SIBLINGS, &p, None).unwrap().position;
+        assert_eq!(at(Placement::Top), 50.0);
+        assert_eq!(at(Placement::Bottom), 300.0 + POSITION_GAP);
+        assert_eq!(at(Placement::Before("a".into())), 50.0);
+        assert_eq!(at(Placement::Before("c".into())), 250.0);
+        assert_eq!(at(Placement::After("a".into())), 150.0);
+        assert_eq!(at(Placement::After("c".into())), 300.0 + POSITION_GAP);
+        assert_eq!(at(Placement::At(7.0)), 7.0);
+        assert_eq!(position_for(&[], &Placement::Top, None).unwrap().position, POSITION_GAP);
+        assert!(position_for(SIBLINGS, &Placement::After("x".into()), None).is_err());
+    }
+
+    #[test]
+    fn test_position_for_renumbers_crowded_siblings() {
+        let siblings = [("a", Some(0.0)), ("b", Some(1.5)), ("c", Some(3.0 * POSITION_GAP))];
+        let slot = position_for(&siblings, &Placement::Top, None).unwrap();
+        assert_eq!(slot.position, POSITION_GAP);
+        assert_eq!(
+            slot.renumber,
+            vec![("a".to_string(), 2.0 * POSITION_GAP), ("b".to_string(), 3.0 * POSITION_GAP), ("c".to_string(), 4.0 * POSITION_GAP)]
+        );
+
+        let slot = position_for(&siblings, &Placement::After("a".into()), None).unwrap();
+        assert_eq!(slot.position, 2.0 * POSITION_GAP);
+        assert_eq!(
+            slot.renumber,
+            vec![("a".to_string(), POSITION_GAP), ("b".to_string(), 3.0 * POSITION_GAP), ("c".to_string(), 4.0 * POSITION_GAP)]
+        );
+    }
+
+    #[test]
+    fn test_reorder_updates_only_moved_items() {
+        let siblings = [("a", Some(POSITION_GAP)), ("b", Some(2.0 * POSITION_GAP)), ("c", Some(10.0))];
+        let order = vec!["a".to_string(), "c".to_string(), "b".to_string()];
+        let plan = reorder(&siblings, &order).unwrap();
+        assert_eq!(plan, vec![("c".to_string(), 2.0 * POSITION_GAP), ("b".to_string(), 3.0 * POSITION_GAP)]);
+
+        let duplicate = vec!["a".to_string(), "a".to_string(), "b".to_string()];
+        assert!(reorder(&siblings, &duplicate).is_err());
+        assert!(reorder(&siblings, &order[..2]).is_err());
+    }
+
+    #[test]
+    fn test_position_for_ignores_moving_item() {
+        let at = |p: Placement, moving| position_for(SIBLINGS, &p, Some(moving)).unwrap();
+        assert_eq!(at(Placement::After("a".into()), "b").position, 200.0);
+        assert_eq!(at(Placement::Bottom, "c").position, 200.0 + POSITION_GAP);
+        assert_eq!(at(Placement::Before("b".into()), "b"), Slot { position: 200.0, renumber: Vec::new() });
+        assert_eq!(at(Placement::After("b".into()), "b").position, 200.0);
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::BTreeMap;
+use tracing::{debug, warn};
+
+use super::client::{PlankaClient, PlankaError};
+
+/// Planka clients keyed by instance name, one of which is the default
+#[derive(Debug)]
+pub struct ClientRegistry {
+    clients: BTreeMap<String, PlankaClient>,
+    default: String,
+}
+
+impl ClientRegistry {
+    /// Creates a registry holding a single client as the default instance
+    pub fn single(name: impl Into<String>, client: PlankaClient) -> Self {
+        let name = name.into();
+        let mut clients = BTreeMap::new();
+        clients.insert(name.clone(), client);
+        Self { clients, default: name }
+    }
+
+    /// Adds another instance; an existing instance with the same name is replaced
+    pub fn insert(&mut self, name: impl Into<String>, client: PlankaClient) {
+        let name = name.into();
+        if self.clients.insert(name.clone(), client).is_some() {
+            warn!(instance = %name, "Replacing already registered Planka instance");
+        }
+    }
+
+    /// Creates a registry for the default instance that authenticates with the
+    /// given token. Other instances are left out, as the token only applies to
+    /// the server that issued it.
+    pub fn for_token(&self, token: &str) -> Self {
+        let client = &self.clients[&self.default];
+        Self::single(self.default.clone(), client.with_token(token))
+    }
+
+    /// Looks up an instance by name, falling back to the default instance
+    pub fn get(&self, name: Option<&str>) -> Result<&PlankaClient, PlankaError> {
+        let name = name.unwrap_or(&self.default);
+        debug!(instance = %name, "Resolving Planka instance");
+        self.clients
+            .get(name)
+            .ok_or_else(|| PlankaError::Config(format!("Unknown instance: {name}")))
+    }
+
+    pub fn default_name(&self) -> &str {
+        &self.default
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &PlankaClient)> {
+        self.clients.iter().map(|(name, client)| (name.as_str(), client))
+    }
+
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Revokes the access tokens of every instance, logging failures
+    pub async fn logout_all(&self) {
+        for (name, client) in &self.clients {
+            if let Err(e) = client.logout().await {
+                warn!(instance = %name, error = %e, "Failed to revoke access token");
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use super::types::{Board, Project};
+
+/// Restricts the projects and boards the client is allowed to see or modify.
+///
+/// Entries match either an id exactly or a name case-insensitively. An empty
+/// project list allows every project, and an empty board list allows every
+/// board of an allowed project.
+#[derive(Debug, Clone, Default)]
+pub struct Scope {
+    projects: Vec<String>,
+    boards: Vec<String>,
+}
+
+impl Scope {
+    pub fn new(projects: Vec<String>, boards: Vec<String>) -> Self {
+        Self { projects, boards }
+    }
+
+    pub fn is_unrestricted(&self) -> bool {
+        self.projects.is_empty() && self.boards.is_empty()
+    }
+
+    pub fn projects(&self) -> &[String] {
+        &self.projects
+    }
+
+    pub fn boards(&self) -> &[String] {
+        &self.boards
+    }
+
+    /// Whether the project itself passes the project filter
+    pub fn allows_project(&self, project: &Project) -> bool {
+        self.allows_project_name(&project.id, &project.name)
+    }
+
+    /// Whether a project with this id and name passes the project filter
+    pub fn allows_project_name(&self, id: &str, name: &str) -> bool {
+        self.projects.is_empty() || matches_any(&self.projects, id, name)
+    }
+
+    /// Whether a board passes the board filter (its project must be checked separately)
+    pub fn allows_board(&self, board: &Board) -> bool {
+        self.allows_board_name(&board.id, &board.name)
+    }
+
+    /// Whether a board with this id and name passes the board filter
+    pub fn allows_board_name(&self, id: &str, name: &str) -> bool {
+        self.boards.is_empty() || matches_any(&self.boards, id, name)
+    }
+}
+
+fn matches_any(entries: &[String], id: &str, name: &str) -> bool {
+    entries
+        .iter()
+        .any(|e| e == id || e.eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(id: &str, name: &str) -> Project {
+        Project {
+            id: id.to_string(),
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn board(id: &str, name: &str) -> Board {
+        Board {
+            id: id.to_string(),
+            name: name.to_string(),
+            position: None,
+            project_id: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_empty_scope_allows_everything() {
+        let scope = Scope::default();
+        assert!(scope.is_unrestricted());
+        assert!(scope.allows_project(&project("1", "HR")));
+        assert!(scope.allows_board(&board("2", "Payroll")));
+    }
+
+    #[test]
+    fn test_scope_matches_ids_and_names() {
+        let scope = Scope::new(vec!["engineering".into(), "42".into()], vec![]);
+        assert!(scope.allows_project(&project("1", "Engineering")));
+        assert!(scope.allows_project(&project("42", "Platform")));
+        assert!(!scope.allows_project(&project("7", "Finance")));
+    }
+
+    #[test]
+    fn test_board_filter_only_applies_to_boards() {
+        let scope = Scope::new(vec![], vec!["Sprint".into()]);
+        assert!(scope.allows_project(&project("1", "HR")));
+        assert!(scope.allows_board(&board("2", "sprint")));
+        assert!(!scope.allows_board(&board("3", "Payroll")));
+    }
+}
@@ -0,0 +1,721 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use tracing::{debug, info, warn};
+
+use super::client::{PlankaClient, PlankaError};
+use super::position::{Placement, POSITION_GAP};
+use super::types::{
+    BoardResponse, CardType, CreateCardOptions, List, UpdateBoardOptions, UpdateCardOptions, UpdateListOptions,
+};
+
+/// Desired state of a board, written as YAML or JSON.
+///
+/// Lists, labels and cards are matched to existing items by `key`, which may
+/// be a Planka id or the item's current name, and otherwise by `name`. A
+/// matched item whose name differs from the spec is renamed.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BoardSpec {
+    pub name: String,
+    /// Board to reconcile; without it the board is looked up by name in `project_id`
+    #[serde(default)]
+    pub board_id: Option<String>,
+    /// Project holding the board, where it is created if missing
+    #[serde(default)]
+    pub project_id: Option<String>,
+    /// Lists in their desired order
+    #[serde(default)]
+    pub lists: Vec<ListSpec>,
+    #[serde(default)]
+    pub labels: Vec<LabelSpec>,
+    /// Cards that must exist on the board
+    #[serde(default)]
+    pub cards: Vec<CardSpec>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ListSpec {
+    #[serde(default)]
+    pub key: Option<String>,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LabelSpec {
+    #[serde(default)]
+    pub key: Option<String>,
+    pub name: String,
+    pub color: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CardSpec {
+    #[serde(default)]
+    pub key: Option<String>,
+    pub name: String,
+    /// Key or name of a list in the spec
+    pub list: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Keys or names of labels in the spec
+    #[serde(default)]
+    pub labels: Vec<String>,
+}
+
+impl BoardSpec {
+    /// Parses a spec from YAML or JSON (JSON being valid YAML)
+    pub fn parse(text: &str) -> Result<Self, PlankaError> {
+        let spec: BoardSpec =
+            serde_yaml::from_str(text).map_err(|e| PlankaError::Invalid(format!("invalid board spec: {e}")))?;
+        spec.validate()?;
+        Ok(spec)
+    }
+
+    fn validate(&self) -> Result<(), PlankaError> {
+        if self.board_id.is_none() && self.project_id.is_none() {
+            return Err(PlankaError::Invalid("board spec needs a board_id or a project_id".into()));
+        }
+        let lists = self.lists.iter().map(|l| (l.key.as_deref(), l.name.as_str()));
+        let labels = self.labels.iter().map(|l| (l.key.as_deref(), l.name.as_str()));
+        let cards = self.cards.iter().map(|c| (c.key.as_deref(), c.name.as_str()));
+        for (kind, entries) in [("list", lists.collect::<Vec<_>>()), ("label", labels.collect()), ("card", cards.collect())] {
+            let mut seen = HashSet::new();
+            for (key, name) in entries {
+                if !seen.insert(key.unwrap_or(name)) {
+                    return Err(PlankaError::Invalid(format!("duplicate {kind} {:?} in board spec", key.unwrap_or(name))));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An item that exists already or is created by an earlier change, by its
+/// index in the spec
+#[derive(Debug, Clone, PartialEq)]
+pub enum ItemRef {
+    Existing(String),
+    New(usize),
+}
+
+/// One step of reconciling a board toward a spec
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    CreateBoard { name: String },
+    RenameBoard { from: String, to: String },
+    CreateList { index: usize, name: String, position: f64 },
+    RenameList { id: String, from: String, to: String },
+    MoveList { id: String, name: String, position: f64 },
+    DeleteList { id: String, name: String, cards: usize },
+    CreateLabel { index: usize, name: String, color: String, position: f64 },
+    UpdateLabel { id: String, from: String, name: String, color: String },
+    DeleteLabel { id: String, name: String },
+    CreateCard { index: usize, list: ItemRef, list_name: String, name: String, description: Option<String> },
+    RenameCard { id: String, from: String, to: String },
+    UpdateCardDescription { id: String, name: String, description: String },
+    MoveCard { id: String, name: String, list: ItemRef, list_name: String },
+    AddCardLabel { card: ItemRef, card_name: String, label: ItemRef, label_name: String },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::CreateBoard { name } => write!(f, "+ board {name:?}"),
+            Change::RenameBoard { from, to } => write!(f, "~ board {from:?} -> {to:?}"),
+            Change::CreateList { name, .. } => write!(f, "+ list {name:?}"),
+            Change::RenameList { from, to, .. } => write!(f, "~ list {from:?} -> {to:?}"),
+            Change::MoveList { name, position, .. } => write!(f, "~ list {name:?} moved to position {position}"),
+            Change::DeleteList { name, cards, .. } => write!(f, "- list {name:?} ({cards} card(s))"),
+            Change::CreateLabel { name, color, .. } => write!(f, "+ label {name:?} ({color})"),
+            Change::UpdateLabel { from, name, color, .. } => write!(f, "~ label {from:?} -> {name:?} ({color})"),
+            Change::DeleteLabel { name, .. } => write!(f, "- label {name:?}"),
+            Change::CreateCard { name, list_name, .. } => write!(f, "+ card {name:?} in list {list_name:?}"),
+            Change::RenameCard { from, to, .. } => write!(f, "~ card {from:?} -> {to:?}"),
+            Change::UpdateCardDescription { name, .. } => write!(f, "~ card {name:?} description"),
+            Change::MoveCard { name, list_name, .. } => write!(f, "~ card {name:?} moved to list {list_name:?}"),
+            Change::AddCardLabel { card_name, label_name, .. } => write!(f, "+ card {card_name:?} label {label_name:?}"),
+        }
+    }
+}
+
+/// Options for applying a board spec
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ApplyOptions {
+    /// Delete lists and labels missing from the spec
+    pub prune: bool,
+    /// Only compute the changes
+    pub dry_run: bool,
+}
+
+/// The changes a spec needs on a board, computed once so that exactly the
+/// previewed changes are applied
+#[derive(Debug, Clone)]
+pub struct SpecPlan {
+    /// The existing board, or `None` when the plan creates it
+    pub board_id: Option<String>,
+    pub changes: Vec<Change>,
+}
+
+impl SpecPlan {
+    /// A report listing the planned changes, none of them applied yet
+    pub fn report(&self, spec: &BoardSpec, dry_run: bool) -> ApplyReport {
+        ApplyReport {
+            board_name: spec.name.clone(),
+            board_id: self.board_id.clone(),
+            changes: self.changes.iter().map(Change::to_string).collect(),
+            applied: 0,
+            dry_run,
+            error: None,
+        }
+    }
+}
+
+/// Outcome of applying a board spec
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyReport {
+    pub board_name: String,
+    pub board_id: Option<String>,
+    pub changes: Vec<String>,
+    /// Number of changes carried out, in order
+    pub applied: usize,
+    pub dry_run: bool,
+    /// Error that stopped the run; later changes were not attempted
+    pub error: Option<String>,
+}
+
+impl ApplyReport {
+    /// Renders the report as a diff followed by a summary line
+    pub fn to_text(&self) -> String {
+        format!("{}{}", self.diff(), self.summary())
+    }
+
+    /// A header line and one line per change
+    pub fn diff(&self) -> String {
+        let board = match &self.board_id {
+            Some(id) => format!("{:?} (board {id})", self.board_name),
+            None => format!("{:?} (new board)", self.board_name),
+        };
+        let mut out = format!("Board spec for {board}: {} change(s)\n", self.changes.len());
+        for change in &self.changes {
+            out.push_str(change);
+            out.push('\n');
+        }
+        out
+    }
+
+    pub fn summary(&self) -> String {
+        match &self.error {
+            Some(e) => format!("Failed after applying {} of {} change(s): {e}", self.applied, self.changes.len()),
+            None if self.changes.is_empty() => "No changes needed.".to_string(),
+            None if self.dry_run => "Dry run: nothing was changed.".to_string(),
+            None => format!("Applied {} change(s).", self.applied),
+        }
+    }
+}
+
+/// Finds the existing item for a spec entry among `(id, name)` pairs: by key
+/// as id or name, then by name. Items matched by an earlier entry are skipped.
+fn find_match<'a>(items: &[(&'a str, &'a str)], key: Option<&str>, name: &str, taken: &HashSet<&str>) -> Option<&'a str> {
+    let free = items.iter().filter(|(id, _)| !taken.contains(id));
+    let by_key = key.and_then(|key| {
+        free.clone()
+            .find(|(id, _)| *id == key)
+            .or_else(|| free.clone().find(|(_, n)| n.eq_ignore_ascii_case(key)))
+    });
+    by_key
+        .or_else(|| free.clone().find(|(_, n)| n.eq_ignore_ascii_case(name)))
+        .map(|(id, _)| *id)
+}
+
+/// Resolves a reference from a card to a spec entry by key or name
+fn find_entry<'a>(entries: &[(Option<&str>, &'a str)], refs: &[ItemRef], reference: &str) -> Option<(ItemRef, &'a str)> {
+    entries
+        .iter()
+        .position(|(key, _)| *key == Some(reference))
+        .or_else(|| entries.iter().position(|(_, name)| name.eq_ignore_ascii_case(reference)))
+        .map(|i| (refs[i].clone(), entries[i].1))
+}
+
+/// Target positions for items in their desired order, given their current
+/// positions (`None` for items still to be created). When the existing items
+/// are already in order they keep their positions and new items fill the
+/// gaps; otherwise every item is renumbered.
+pub fn layout(current: &[Option<f64>]) -> Vec<f64> {
+    let known: Vec<f64> = current.iter().flatten().copied().collect();
+    if known.windows(2).any(|w| w[0] >= w[1]) || known.first().is_some_and(|p| *p <= 0.0) {
+        return (1..=current.len()).map(|i| i as f64 * POSITION_GAP).collect();
+    }
+
+    let mut positions = Vec::with_capacity(current.len());
+    let mut previous = 0.0;
+    let mut i = 0;
+    while i < current.len() {
+        if let Some(position) = current[i] {
+            positions.push(position);
+            previous = position;
+            i += 1;
+            continue;
+        }
+        let end = (i..current.len()).find(|&j| current[j].is_some()).unwrap_or(current.len());
+        let step = match current.get(end).copied().flatten() {
+            Some(next) => (next - previous) / (end - i + 1) as f64,
+            None => POSITION_GAP,
+        };
+        positions.extend((1..=end - i).map(|k| previous + step * k as f64));
+        i = end;
+    }
+    positions
+}
+
+/// Computes the changes that bring `board` (or a board still to be created)
+/// in line with `spec`
+pub fn plan(spec: &BoardSpec, board: Option<&BoardResponse>, prune: bool) -> Result<Vec<Change>, PlankaError> {
+    let mut changes = Vec::new();
+    let included = board.map(|b| &b.included);
+
+    match board {
+        None => changes.push(Change::CreateBoard { name: spec.name.clone() }),
+        Some(b) if b.item.name != spec.name => changes.push(Change::RenameBoard {
+            from: b.item.name.clone(),
+            to: spec.name.clone(),
+        }),
+        Some(_) => {}
+    }
+
+    // Lists, matched and then laid out in spec order; archive and trash lists have no position
+    let mut lists: Vec<&List> = included
+        .map(|i| i.lists.iter().filter(|l| l.position.is_some()).collect())
+        .unwrap_or_default();
+    lists.sort_by(|a, b| a.position.unwrap_or(0.0).total_cmp(&b.position.unwrap_or(0.0)));
+    let list_items: Vec<(&str, &str)> = lists.iter().map(|l| (l.id.as_str(), l.name.as_str())).collect();
+    let mut taken = HashSet::new();
+    let mut list_refs = Vec::with_capacity(spec.lists.len());
+    let mut sequence: Vec<(ItemRef, &str, Option<f64>)> = Vec::new();
+    for (index, entry) in spec.lists.iter().enumerate() {
+        match find_match(&list_items, entry.key.as_deref(), &entry.name, &taken) {
+            Some(id) => {
+                taken.insert(id);
+                let list = lists.iter().find(|l| l.id == id).expect("matched list exists");
+                if list.name != entry.name {
+                    changes.push(Change::RenameList {
+                        id: id.to_string(),
+                        from: list.name.clone(),
+                        to: entry.name.clone(),
+                    });
+                }
+                list_refs.push(ItemRef::Existing(id.to_string()));
+                sequence.push((ItemRef::Existing(id.to_string()), &entry.name, list.position));
+            }
+            None => {
+                list_refs.push(ItemRef::New(index));
+                sequence.push((ItemRef::New(index), &entry.name, None));
+            }
+        }
+    }
+    let extra_lists: Vec<&List> = lists.iter().filter(|l| !taken.contains(l.id.as_str())).copied().collect();
+    if !prune {
+        sequence.extend(extra_lists.iter().map(|l| (ItemRef::Existing(l.id.clone()), l.name.as_str(), l.position)));
+    }
+    let positions = layout(&sequence.iter().map(|(_, _, p)| *p).collect::<Vec<_>>());
+    for ((item, name, current), position) in sequence.into_iter().zip(positions) {
+        match item {
+            ItemRef::New(index) => changes.push(Change::CreateList {
+                index,
+                name: name.to_string(),
+                position,
+            }),
+            ItemRef::Existing(id) if current != Some(position) => changes.push(Change::MoveList {
+                id,
+                name: name.to_string(),
+                position,
+            }),
+            ItemRef::Existing(_) => {}
+        }
+    }
+
+    // Labels
+    let labels = included.map(|i| i.labels.as_slice()).unwrap_or_default();
+    let label_items: Vec<(&str, &str)> = labels
+        .iter()
+        .map(|l| (l.id.as_str(), l.name.as_deref().unwrap_or_default()))
+        .collect();
+    let mut next_label_position = labels.iter().filter_map(|l| l.position).fold(0.0, f64::max);
+    let mut taken = HashSet::new();
+    let mut label_refs = Vec::with_capacity(spec.labels.len());
+    for (index, entry) in spec.labels.iter().enumerate() {
+        match find_match(&label_items, entry.key.as_deref(), &entry.name, &taken) {
+            Some(id) => {
+                taken.insert(id);
+                let label = labels.iter().find(|l| l.id == id).expect("matched label exists");
+                if label.name.as_deref() != Some(entry.name.as_str()) || label.color != entry.color {
+                    changes.push(Change::UpdateLabel {
+                        id: id.to_string(),
+                        from: label.name.clone().unwrap_or_else(|| label.color.clone()),
+                        name: entry.name.clone(),
+                        color: entry.color.clone(),
+                    });
+                }
+                label_refs.push(ItemRef::Existing(id.to_string()));
+            }
+            None => {
+                next_label_position += POSITION_GAP;
+                changes.push(Change::CreateLabel {
+                    index,
+                    name: entry.name.clone(),
+                    color: entry.color.clone(),
+                    position: next_label_position,
+                });
+                label_refs.push(ItemRef::New(index));
+            }
+        }
+    }
+    let extra_labels: Vec<_> = labels.iter().filter(|l| !taken.contains(l.id.as_str())).collect();
+
+    // Cards, which must refer to lists and labels of the spec
+    let cards = included.map(|i| i.cards.as_slice()).unwrap_or_default();
+    let card_labels = included.map(|i| i.card_labels.as_slice()).unwrap_or_default();
+    let card_items: Vec<(&str, &str)> = cards.iter().map(|c| (c.id.as_str(), c.name.as_str())).collect();
+    let list_entries: Vec<(Option<&str>, &str)> = spec.lists.iter().map(|l| (l.key.as_deref(), l.name.as_str())).collect();
+    let label_entries: Vec<(Option<&str>, &str)> =
+        spec.labels.iter().map(|l| (l.key.as_deref(), l.name.as_str())).collect();
+    let mut taken = HashSet::new();
+    for (index, entry) in spec.cards.iter().enumerate() {
+        let (list, list_name) = find_entry(&list_entries, &list_refs, &entry.list).ok_or_else(|| {
+            PlankaError::Invalid(format!("card {:?} refers to unknown list {:?}", entry.name, entry.list))
+        })?;
+        let wanted_labels = entry
+            .labels
+            .iter()
+            .map(|l| {
+                find_entry(&label_entries, &label_refs, l).ok_or_else(|| {
+                    PlankaError::Invalid(format!("card {:?} refers to unknown label {l:?}", entry.name))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let card_ref = match find_match(&card_items, entry.key.as_deref(), &entry.name, &taken) {
+            Some(id) => {
+                taken.insert(id);
+                let card = cards.iter().find(|c| c.id == id).expect("matched card exists");
+                if card.name != entry.name {
+                    changes.push(Change::RenameCard {
+                        id: id.to_string(),
+                        from: card.name.clone(),
+                        to: entry.name.clone(),
+                    });
+                }
+                if let Some(description) = entry.description.as_ref().filter(|d| card.description.as_ref() != Some(*d)) {
+                    changes.push(Change::UpdateCardDescription {
+                        id: id.to_string(),
+                        name: entry.name.clone(),
+                        description: description.clone(),
+                    });
+                }
+                if list != ItemRef::Existing(card.list_id.clone()) {
+                    changes.push(Change::MoveCard {
+                        id: id.to_string(),
+                        name: entry.name.clone(),
+                        list,
+                        list_name: list_name.to_string(),
+                    });
+                }
+                ItemRef::Existing(id.to_string())
+            }
+            None => {
+                changes.push(Change::CreateCard {
+                    index,
+                    list,
+                    list_name: list_name.to_string(),
+                    name: entry.name.clone(),
+                    description: entry.description.clone(),
+                });
+                ItemRef::New(index)
+            }
+        };
+        for (label, label_name) in wanted_labels {
+            let attached = match (&card_ref, &label) {
+                (ItemRef::Existing(card_id), ItemRef::Existing(label_id)) => card_labels
+                    .iter()
+                    .any(|cl| &cl.card_id == card_id && &cl.label_id == label_id),
+                _ => false,
+            };
+            if !attached {
+                changes.push(Change::AddCardLabel {
+                    card: card_ref.clone(),
+                    card_name: entry.name.clone(),
+                    label,
+                    label_name: label_name.to_string(),
+                });
+            }
+        }
+    }
+
+    if prune {
+        for list in extra_lists {
+            let remaining = cards
+                .iter()
+                .filter(|c| c.list_id == list.id && !taken.contains(c.id.as_str()))
+                .count();
+            changes.push(Change::DeleteList {
+                id: list.id.clone(),
+                name: list.name.clone(),
+                cards: remaining,
+            });
+        }
+        for label in extra_labels {
+            changes.push(Change::DeleteLabel {
+                id: label.id.clone(),
+                name: label.name.clone().unwrap_or_else(|| label.color.clone()),
+            });
+        }
+    }
+    Ok(changes)
+}
+
+/// Ids of the items created while applying a spec, by spec index
+#[derive(Debug, Default)]
+struct Created {
+    lists: HashMap<usize, String>,
+    labels: HashMap<usize, String>,
+    cards: HashMap<usize, String>,
+}
+
+fn resolve(item: &ItemRef, created: &HashMap<usize, String>) -> Result<String, PlankaError> {
+    match item {
+        ItemRef::Existing(id) => Ok(id.clone()),
+        ItemRef::New(index) => created
+            .get(index)
+            .cloned()
+            .ok_or_else(|| PlankaError::Invalid(format!("spec item {index} was not created"))),
+    }
+}
+
+impl PlankaClient {
+    /// Reconciles a board toward a spec. Changes are applied in order and
+    /// stop at the first failure; as matching is by key and name, running
+    /// the spec again continues where it stopped.
+    pub async fn apply_board_spec(&self, spec: &BoardSpec, options: ApplyOptions) -> Result<ApplyReport, PlankaError> {
+        info!(name = %spec.name, prune = options.prune, dry_run = options.dry_run, "Applying board spec");
+        let plan = self.plan_board_spec(spec, options.prune).await?;
+        if options.dry_run {
+            return Ok(plan.report(spec, true));
+        }
+        Ok(self.apply_changes(spec, &plan).await)
+    }
+
+    /// Computes the changes a spec needs without applying any of them
+    pub async fn plan_board_spec(&self, spec: &BoardSpec, prune: bool) -> Result<SpecPlan, PlankaError> {
+        let board = self.find_spec_board(spec).await?;
+        let changes = plan(spec, board.as_ref(), prune)?;
+        debug!(changes = changes.len(), "Planned board spec changes");
+        Ok(SpecPlan {
+            board_id: board.map(|b| b.item.id),
+            changes,
+        })
+    }
+
+    /// Applies the changes of a plan in order, stopping at the first failure
+    pub async fn apply_changes(&self, spec: &BoardSpec, plan: &SpecPlan) -> ApplyReport {
+        let mut report = plan.report(spec, false);
+        let mut created = Created::default();
+        for change in &plan.changes {
+            debug!(change = %change, "Applying board spec change");
+            if let Err(e) = self.apply_change(spec, change, &mut report, &mut created).await {
+                warn!(change = %change, applied = report.applied, error = %e, "Board spec change failed");
+                report.error = Some(e.to_string());
+                return report;
+            }
+            report.applied += 1;
+        }
+        info!(board_id = ?report.board_id, applied = report.applied, "Board spec applied successfully");
+        report
+    }
+
+    /// Finds the board a spec describes, or `None` when it is still to be created
+    async fn find_spec_board(&self, spec: &BoardSpec) -> Result<Option<BoardResponse>, PlankaError> {
+        if let Some(board_id) = spec.board_id.as_deref() {
+            self.ensure_board_in_scope(board_id).await?;
+            return self.fetch_board(board_id, true).await.map(Some);
+        }
+        let project_id = spec
+            .project_id
+            .as_deref()
+            .ok_or_else(|| PlankaError::Invalid("board spec needs a board_id or a project_id".into()))?;
+        let boards = self.list_boards(project_id, true).await?;
+        match boards.iter().find(|b| b.name.eq_ignore_ascii_case(&spec.name)) {
+            Some(board) => self.fetch_board(&board.id, true).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    async fn apply_change(
+        &self,
+        spec: &BoardSpec,
+        change: &Change,
+        report: &mut ApplyReport,
+        created: &mut Created,
+    ) -> Result<(), PlankaError> {
+        let board_id = || {
+            report
+                .board_id
+                .clone()
+                .ok_or_else(|| PlankaError::Invalid("board was not created".into()))
+        };
+        match change {
+            Change::CreateBoard { name } => {
+                let project_id = spec.project_id.as_deref().unwrap_or_default();
+                let board = self.create_board(project_id, name).await?;
+                report.board_id = Some(board.id);
+            }
+            Change::RenameBoard { to, .. } => {
+                let options = UpdateBoardOptions {
+                    name: Some(to.clone()),
+                    ..Default::default()
+                };
+                self.update_board(&board_id()?, options).await?;
+            }
+            Change::CreateList { index, name, position } => {
+                let list = self.create_list_at(&board_id()?, name, *position).await?;
+                created.lists.insert(*index, list.id);
+            }
+            Change::RenameList { id, to, .. } => {
+                let options = UpdateListOptions {
+                    name: Some(to.clone()),
+                    ..Default::default()
+                };
+                self.update_list(id, options).await?;
+            }
+            Change::MoveList { id, position, .. } => {
+                let options = UpdateListOptions {
+                    placement: Some(Placement::At(*position)),
+                    ..Default::default()
+                };
+                self.update_list(id, options).await?;
+            }
+            Change::DeleteList { id, .. } => self.delete_list(id).await?,
+            Change::CreateLabel { index, name, color, position } => {
+                let label = self.create_label(&board_id()?, Some(name), color, *position).await?;
+                created.labels.insert(*index, label.id);
+            }
+            Change::UpdateLabel { id, name, color, .. } => {
+                self.update_label(id, Some(name), color).await?;
+            }
+            Change::DeleteLabel { id, .. } => self.delete_label(&board_id()?, id).await?,
+            Change::CreateCard { index, list, name, description, .. } => {
+                let card = self
+                    .create_card(CreateCardOptions {
+                        list_id: resolve(list, &created.lists)?,
+                        placement: Placement::Bottom,
+                        card_type: CardType::Project,
+                        name: name.clone(),
+                        description: description.clone(),
+                        due_date: None,
+                        is_due_completed: None,
+                        stopwatch: None,
+                    })
+                    .await?;
+                created.cards.insert(*index, card.id);
+            }
+            Change::RenameCard { id, to, .. } => {
+                let options = UpdateCardOptions {
+                    name: Some(to.clone()),
+                    ..Default::default()
+                };
+                self.update_card(id, options).await?;
+            }
+            Change::UpdateCardDescription { id, description, .. } => {
+                let options = UpdateCardOptions {
+                    description: Some(description.clone()),
+                    ..Default::default()
+                };
+                self.update_card(id, options).await?;
+            }
+            Change::MoveCard { id, list, .. } => {
+                self.move_card(id, &resolve(list, &created.lists)?, Placement::Bottom).await?;
+            }
+            Change::AddCardLabel { card, label, .. } => {
+                let card_id = resolve(card, &created.cards)?;
+                self.add_card_label(&card_id, &resolve(label, &created.labels)?).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::planka::test_support::BoardBuilder;
+
+    fn board() -> BoardResponse {
+        BoardBuilder::new("Sprint")
+            .list("10", "Todo", Some(100.0))
+            .list("11", "Doing", Some(200.0))
+            .list("12", "Old", Some(300.0))
+            .card("100", "12", "Onboarding", None)
+            .card("101", "12", "Leftover", None)
+            .label("50", "Bug", "berry-red", Some(1.0))
+            .card_label("70", "100", "50")
+            .build()
+    }
+
+    #[test]
+    fn test_layout_fills_gaps_or_renumbers() {
+        assert_eq!(layout(&[Some(100.0), None, Some(200.0), None]), vec![100.0, 150.0, 200.0, 200.0 + POSITION_GAP]);
+        assert_eq!(layout(&[None, None, Some(300.0)]), vec![100.0, 200.0, 300.0]);
+        assert_eq!(layout(&[Some(200.0), Some(100.0)]), vec![POSITION_GAP, 2.0 * POSITION_GAP]);
+    }
+
+    #[test]
+    fn test_plan_renames_by_key_creates_and_prunes() {
+        let spec = BoardSpec::parse(
+            r#"
+name: Sprint
+board_id: "1"
+lists:
+  - key: Todo
+    name: Backlog
+  - name: Review
+  - name: Doing
+labels:
+  - name: Bug
+    color: berry-red
+cards:
+  - name: Onboarding
+    list: Backlog
+    labels: [Bug]
+"#,
+        )
+        .unwrap();
+        let changes: Vec<String> = plan(&spec, Some(&board()), true).unwrap().iter().map(|c| c.to_string()).collect();
+        assert_eq!(
+            changes,
+            vec![
+                "~ list \"Todo\" -> \"Backlog\"",
+                "+ list \"Review\"",
+                "~ card \"Onboarding\" moved to list \"Backlog\"",
+                "- list \"Old\" (1 card(s))",
+            ]
+        );
+
+        let unchanged = plan(&spec, Some(&board()), false).unwrap();
+        assert!(!unchanged.iter().any(|c| matches!(c, Change::DeleteList { .. })));
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_references() {
+        assert!(BoardSpec::parse(r#"{"name": "X", "lists": []}"#).is_err());
+        let spec = BoardSpec::parse(r#"{"name": "X", "project_id": "1", "cards": [{"name": "C", "list": "Nope"}]}"#).unwrap();
+        assert!(plan(&spec, None, false).is_err());
+        let spec = BoardSpec::parse(
+            r#"{"name": "X", "project_id": "1", "lists": [{"name": "Todo"}], "cards": [{"name": "C", "list": "todo"}]}"#,
+        )
+        .unwrap();
+        assert!(plan(&spec, None, false).is_ok());
+    }
+}
@@ -0,0 +1,62 @@
+//! Fixtures shared by unit tests
+
+use serde_json::{json, Value};
+
+use super::types::BoardResponse;
+
+/// Builds a `BoardResponse` as `GET /api/boards/{id}` returns it. The board
+/// has id `"1"`; cards default to the `project` type.
+pub struct BoardBuilder {
+    data: Value,
+}
+
+impl BoardBuilder {
+    pub fn new(name: &str) -> Self {
+        Self {
+            data: json!({"item": {"id": "1", "name": name}, "included": {}}),
+        }
+    }
+
+    /// Adds a raw item to an included collection such as `"users"` or `"tasks"`
+    pub fn included(mut self, collection: &str, item: Value) -> Self {
+        let items = self.data["included"]
+            .as_object_mut()
+            .expect("included is an object")
+            .entry(collection)
+            .or_insert_with(|| json!([]));
+        items.as_array_mut().expect("collection is an array").push(item);
+        self
+    }
+
+    pub fn list(self, id: &str, name: &str, position: Option<f64>) -> Self {
+        self.included("lists", json!({"id": id, "boardId": "1", "name": name, "position": position}))
+    }
+
+    pub fn card(self, id: &str, list_id: &str, name: &str, position: Option<f64>) -> Self {
+        self.card_with(id, list_id, name, position, json!({}))
+    }
+
+    /// Adds a card with further fields, which override the defaults
+    pub fn card_with(self, id: &str, list_id: &str, name: &str, position: Option<f64>, fields: Value) -> Self {
+        let mut card = json!({"id": id, "listId": list_id, "name": name, "type": "project", "position": position});
+        if let (Some(card), Value::Object(fields)) = (card.as_object_mut(), fields) {
+            card.extend(fields);
+        }
+        self.included("cards", card)
+    }
+
+    pub fn label(self, id: &str, name: &str, color: &str, position: Option<f64>) -> Self {
+        self.included(
+            "labels",
+            json!({"id": id, "boardId": "1", "name": name, "color": color, "position": position}),
+        )
+    }
+
+    pub fn card_label(self, id: &str, card_id: &str, label_id: &str) -> Self {
+        self.included("cardLabels", json!({"id": id, "cardId": card_id, "labelId": label_id}))
+    }
+
+    pub fn build(self) -> BoardResponse {
+        serde_json::from_value(self.data).expect("valid board fixture")
+    }
+}
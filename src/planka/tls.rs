@@ -0,0 +1,273 @@
+use reqwest::{Certificate, ClientBuilder, Identity};
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::client::WebPkiServerVerifier;
+use rustls::crypto::{verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime};
+use rustls::{DigitallySignedStruct, RootCertStore, SignatureScheme};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use std::sync::Arc;
+use tracing::{debug, error, info, warn};
+
+use super::client::PlankaError;
+use super::config::ClientConfig;
+
+/// Applies the TLS settings of a profile to the HTTP client builder.
+///
+/// Extra root certificates and client identities go through reqwest's own
+/// options. Certificate pinning needs a custom verifier, so in that case a
+/// complete rustls configuration is built and handed to reqwest instead.
+pub fn configure(builder: ClientBuilder, config: &ClientConfig) -> Result<ClientBuilder, PlankaError> {
+    let disable_ssl = config.disable_ssl.unwrap_or(false);
+
+    let roots = match config.ca_cert.as_deref() {
+        Some(path) => load_ca_certs(path)?,
+        None => Vec::new(),
+    };
+    let identity = match config.client_cert.as_deref() {
+        Some(cert) => Some(load_identity(cert, config.client_key.as_deref())?),
+        None => None,
+    };
+    let pins = config
+        .pinned_certs
+        .iter()
+        .map(|p| parse_pin(p))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if pins.is_empty() {
+        let mut builder = builder.danger_accept_invalid_certs(disable_ssl);
+        for der in &roots {
+            builder = builder.add_root_certificate(Certificate::from_der(der)?);
+        }
+        if let Some(identity) = identity {
+            builder = builder.identity(Identity::from_pem(&identity.pem)?);
+        }
+        return Ok(builder);
+    }
+
+    info!(pins = pins.len(), "Enabling certificate pinning");
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+
+    let inner = if disable_ssl {
+        warn!("Chain validation is disabled, relying on pinned certificates only");
+        None
+    } else {
+        let mut store = RootCertStore {
+            roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+        };
+        for der in roots {
+            store.add(der).map_err(tls_error)?;
+        }
+        Some(
+            WebPkiServerVerifier::builder_with_provider(Arc::new(store), provider.clone())
+                .build()
+                .map_err(tls_error)?,
+        )
+    };
+
+    let verifier = PinnedVerifier {
+        inner,
+        pins,
+        provider: provider.clone(),
+    };
+
+    let tls = rustls::ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()
+        .map_err(tls_error)?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(verifier));
+    let tls = match identity {
+        Some(identity) => tls
+            .with_client_auth_cert(identity.certs, identity.key)
+            .map_err(tls_error)?,
+        None => tls.with_no_client_auth(),
+    };
+
+    Ok(builder.use_preconfigured_tls(tls))
+}
+
+fn tls_error(e: impl std::fmt::Display) -> PlankaError {
+    error!(error = %e, "Failed to configure TLS");
+    PlankaError::Config(format!("TLS configuration error: {e}"))
+}
+
+/// Loads every certificate from a PEM file, or from all `.pem`, `.crt` and
+/// `.cer` files in a directory
+fn load_ca_certs(path: &Path) -> Result<Vec<CertificateDer<'static>>, PlankaError> {
+    let files = if path.is_dir() {
+        let entries = std::fs::read_dir(path).map_err(|e| {
+            PlankaError::Config(format!("Cannot read CA directory {}: {e}", path.display()))
+        })?;
+        let mut files: Vec<_> = entries
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| {
+                p.extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|e| matches!(e, "pem" | "crt" | "cer"))
+            })
+            .collect();
+        files.sort();
+        files
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    let mut certs = Vec::new();
+    for file in &files {
+        debug!(path = %file.display(), "Loading CA certificates");
+        for cert in CertificateDer::pem_file_iter(file).map_err(|e| {
+            PlankaError::Config(format!("Cannot read CA certificate {}: {e}", file.display()))
+        })? {
+            certs.push(cert.map_err(|e| {
+                PlankaError::Config(format!("Invalid CA certificate in {}: {e}", file.display()))
+            })?);
+        }
+    }
+
+    if certs.is_empty() {
+        error!(path = %path.display(), "No CA certificates found");
+        return Err(PlankaError::Config(format!("No CA certificates found in {}", path.display())));
+    }
+    info!(path = %path.display(), count = certs.len(), "Loaded additional root certificates");
+    Ok(certs)
+}
+
+struct ClientIdentity {
+    /// Certificate chain and key concatenated, as expected by reqwest
+    pem: Vec<u8>,
+    certs: Vec<CertificateDer<'static>>,
+    key: PrivateKeyDer<'static>,
+}
+
+/// Loads a client certificate chain and its private key. The key may live in
+/// the certificate file itself when no separate key file is given.
+fn load_identity(cert_path: &Path, key_path: Option<&Path>) -> Result<ClientIdentity, PlankaError> {
+    let read = |path: &Path| {
+        std::fs::read(path).map_err(|e| {
+            error!(path = %path.display(), error = %e, "Failed to read client identity");
+            PlankaError::Config(format!("Cannot read {}: {e}", path.display()))
+        })
+    };
+
+    let mut pem = read(cert_path)?;
+    if let Some(key_path) = key_path {
+        pem.push(b'\n');
+        pem.extend(read(key_path)?);
+    }
+
+    let certs = CertificateDer::pem_slice_iter(&pem)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| PlankaError::Config(format!("Invalid client certificate: {e}")))?;
+    if certs.is_empty() {
+        return Err(PlankaError::Config(format!(
+            "No certificate found in {}",
+            cert_path.display()
+        )));
+    }
+    let key = PrivateKeyDer::from_pem_slice(&pem)
+        .map_err(|e| PlankaError::Config(format!("Invalid or missing client key: {e}")))?;
+
+    info!(path = %cert_path.display(), "Loaded client certificate for mutual TLS");
+    Ok(ClientIdentity { pem, certs, key })
+}
+
+/// Parses a SHA-256 certificate fingerprint, as printed by
+/// `openssl x509 -noout -fingerprint -sha256`, with an optional `sha256:` prefix
+fn parse_pin(pin: &str) -> Result<[u8; 32], PlankaError> {
+    let invalid = || PlankaError::Config(format!("Invalid pinned certificate fingerprint: {pin}"));
+    let hex: String = pin
+        .trim()
+        .trim_start_matches("sha256:")
+        .chars()
+        .filter(|c| *c != ':')
+        .collect();
+    if hex.len() != 64 || !hex.is_ascii() {
+        return Err(invalid());
+    }
+
+    let mut digest = [0u8; 32];
+    for (i, byte) in digest.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+    }
+    Ok(digest)
+}
+
+/// Requires one of the presented certificates to match a pinned fingerprint,
+/// on top of the regular chain validation when that is enabled
+#[derive(Debug)]
+struct PinnedVerifier {
+    inner: Option<Arc<WebPkiServerVerifier>>,
+    pins: Vec<[u8; 32]>,
+    provider: Arc<CryptoProvider>,
+}
+
+impl ServerCertVerifier for PinnedVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        if let Some(inner) = &self.inner {
+            inner.verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now)?;
+        }
+
+        let pinned = std::iter::once(end_entity)
+            .chain(intermediates)
+            .any(|cert| {
+                let digest: [u8; 32] = Sha256::digest(cert.as_ref()).into();
+                self.pins.contains(&digest)
+            });
+        if !pinned {
+            error!(server = ?server_name, "Server certificate does not match any pinned fingerprint");
+            return Err(rustls::Error::General("certificate pin mismatch".into()));
+        }
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls12_signature(message, cert, dss, &self.provider.signature_verification_algorithms)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        verify_tls13_signature(message, cert, dss, &self.provider.signature_verification_algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.provider
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pin_accepts_openssl_format() {
+        let pin = "sha256:AB:CD:00:11:22:33:44:55:66:77:88:99:AA:BB:CC:DD:EE:FF:00:11:22:33:44:55:66:77:88:99:AA:BB:CC:DD";
+        let digest = parse_pin(pin).unwrap();
+        assert_eq!(digest[0], 0xab);
+        assert_eq!(digest[31], 0xdd);
+    }
+
+    #[test]
+    fn test_parse_pin_rejects_bad_input() {
+        assert!(parse_pin("abcd").is_err());
+        assert!(parse_pin(&"zz".repeat(32)).is_err());
+    }
+}
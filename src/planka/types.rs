@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use super::position::Placement;
+
 /// Card types supported by Planka
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -26,7 +28,7 @@ pub struct Stopwatch {
     pub total: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Project {
     pub id: String,
@@ -34,11 +36,140 @@ pub struct Project {
     #[serde(default)]
     pub slug: Option<String>,
     #[serde(default)]
+    pub description: Option<String>,
+    /// Set for private projects; shared projects have no owner
+    #[serde(default)]
+    pub owner_project_manager_id: Option<String>,
+    /// `gradient` or `image` when a background is set
+    #[serde(default)]
+    pub background_type: Option<String>,
+    #[serde(default)]
+    pub background_gradient: Option<String>,
+    #[serde(default)]
+    pub is_hidden: Option<bool>,
+    #[serde(default)]
     pub created_at: Option<String>,
     #[serde(default)]
     pub updated_at: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+}
+
+/// Access of a user to a board
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardMembership {
+    pub id: String,
+    pub board_id: String,
+    pub user_id: String,
+    /// `editor` or `viewer`
+    pub role: String,
+    /// Whether a viewer may comment
+    #[serde(default)]
+    pub can_comment: Option<bool>,
+}
+
+/// A board member with the user's details
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardMember {
+    pub membership_id: String,
+    pub user_id: String,
+    pub name: Option<String>,
+    pub username: Option<String>,
+    pub role: String,
+    pub can_comment: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Label {
+    pub id: String,
+    pub board_id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    pub color: String,
+    #[serde(default)]
+    pub position: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardLabel {
+    pub id: String,
+    pub card_id: String,
+    pub label_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardMembership {
+    pub id: String,
+    pub card_id: String,
+    pub user_id: String,
+}
+
+/// A checklist on a card
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskList {
+    pub id: String,
+    pub card_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub position: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Task {
+    pub id: String,
+    pub task_list_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub is_completed: bool,
+    #[serde(default)]
+    pub position: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Comment {
+    pub id: String,
+    pub card_id: String,
+    #[serde(default)]
+    pub user_id: Option<String>,
+    pub text: String,
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
+
+/// Response wrapping a single created or updated item
+#[derive(Debug, Clone, Deserialize)]
+pub struct ItemResponse<T> {
+    pub item: T,
+}
+
+/// A user allowed to manage a project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectManager {
+    pub id: String,
+    pub project_id: String,
+    pub user_id: String,
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Board {
@@ -103,13 +234,21 @@ pub struct Card {
 #[serde(rename_all = "camelCase")]
 pub struct ProjectsResponse {
     pub items: Vec<Project>,
+    #[serde(default)]
+    pub included: ProjectsIncluded,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectsIncluded {
+    #[serde(default)]
+    pub boards: Vec<Board>,
 }
 
 /// Response from GET /api/projects/{id} (includes nested boards, lists, cards)
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProjectResponse {
-    #[allow(dead_code)]
     pub item: Project,
     pub included: ProjectIncluded,
 }
@@ -120,6 +259,8 @@ pub struct ProjectIncluded {
     #[serde(default)]
     pub boards: Vec<Board>,
     #[serde(default)]
+    pub project_managers: Vec<ProjectManager>,
+    #[serde(default)]
     #[allow(dead_code)]
     pub lists: Vec<List>,
     #[serde(default)]
@@ -131,7 +272,6 @@ pub struct ProjectIncluded {
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BoardResponse {
-    #[allow(dead_code)]
     pub item: Board,
     pub included: BoardIncluded,
 }
@@ -143,6 +283,20 @@ pub struct BoardIncluded {
     pub lists: Vec<List>,
     #[serde(default)]
     pub cards: Vec<Card>,
+    #[serde(default)]
+    pub board_memberships: Vec<BoardMembership>,
+    #[serde(default)]
+    pub users: Vec<User>,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    #[serde(default)]
+    pub card_labels: Vec<CardLabel>,
+    #[serde(default)]
+    pub card_memberships: Vec<CardMembership>,
+    #[serde(default)]
+    pub task_lists: Vec<TaskList>,
+    #[serde(default)]
+    pub tasks: Vec<Task>,
 }
 
 /// Response from POST /api/lists/{listId}/cards
@@ -174,6 +328,7 @@ pub struct CreateCardRequest {
 #[derive(Debug, Clone)]
 pub struct CreateCardOptions {
     pub list_id: String,
+    pub placement: Placement,
     pub card_type: CardType,
     pub name: String,
     pub description: Option<String>,
@@ -182,7 +337,56 @@ pub struct CreateCardOptions {
     pub stopwatch: Option<Stopwatch>,
 }
 
-/// Response from POST /api/projects/{projectId}/boards
+/// Response from POST /api/projects and PATCH /api/projects/{id}
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectItemResponse {
+    pub item: Project,
+}
+
+/// Request body for creating a project
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateProjectRequest {
+    /// `private` or `shared`
+    #[serde(rename = "type")]
+    pub project_type: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Options for updating a project
+#[derive(Debug, Clone, Default)]
+pub struct UpdateProjectOptions {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// Gradient name, or an empty string to remove the background
+    pub background_gradient: Option<String>,
+}
+
+/// Response from POST /api/projects/{projectId}/project-managers
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectManagerResponse {
+    pub item: ProjectManager,
+}
+
+/// Options for updating a board
+#[derive(Debug, Clone, Default)]
+pub struct UpdateBoardOptions {
+    pub name: Option<String>,
+    pub placement: Option<Placement>,
+}
+
+/// Response from the board membership endpoints
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardMembershipResponse {
+    pub item: BoardMembership,
+}
+
+/// Response from POST /api/projects/{projectId}/boards and PATCH /api/boards/{id}
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BoardCreateResponse {
@@ -202,6 +406,15 @@ pub struct CreateBoardRequest {
 #[serde(rename_all = "camelCase")]
 pub struct ListResponse {
     pub item: List,
+    #[serde(default)]
+    pub included: ListIncluded,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListIncluded {
+    #[serde(default)]
+    pub cards: Vec<Card>,
 }
 
 /// Request body for creating a list
@@ -212,6 +425,13 @@ pub struct CreateListRequest {
     pub position: f64,
 }
 
+/// Options for updating a list
+#[derive(Debug, Clone, Default)]
+pub struct UpdateListOptions {
+    pub name: Option<String>,
+    pub placement: Option<Placement>,
+}
+
 /// Options for updating a card
 #[derive(Debug, Clone, Default)]
 pub struct UpdateCardOptions {
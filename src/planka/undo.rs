@@ -0,0 +1,133 @@
+use std::fmt;
+use tracing::{debug, info};
+
+use super::client::{PlankaClient, PlankaError};
+use super::position::Placement;
+use super::types::{
+    Board, Card, List, Project, UpdateBoardOptions, UpdateCardOptions, UpdateListOptions, UpdateProjectOptions,
+};
+
+/// An action undoing one change: deleting an item that was created, or
+/// writing back the state an item had before it was changed
+#[derive(Debug, Clone)]
+pub enum Compensation {
+    DeleteProject { id: String, name: String },
+    DeleteBoard { id: String },
+    DeleteList { id: String },
+    DeleteCard { id: String },
+    DeleteLabel { board_id: String, id: String },
+    RestoreProject(Project),
+    RestoreBoard(Board),
+    RestoreList(List),
+    RestoreCard(Card),
+}
+
+impl fmt::Display for Compensation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Compensation::DeleteProject { id, name } => write!(f, "delete project {name:?} ({id})"),
+            Compensation::DeleteBoard { id } => write!(f, "delete board {id}"),
+            Compensation::DeleteList { id } => write!(f, "delete list {id}"),
+            Compensation::DeleteCard { id } => write!(f, "delete card {id}"),
+            Compensation::DeleteLabel { board_id, id } => write!(f, "delete label {id} on board {board_id}"),
+            Compensation::RestoreProject(p) => write!(f, "restore project {:?} ({})", p.name, p.id),
+            Compensation::RestoreBoard(b) => write!(f, "restore board {:?} ({})", b.name, b.id),
+            Compensation::RestoreList(l) => write!(f, "restore list {:?} ({})", l.name, l.id),
+            Compensation::RestoreCard(c) => write!(f, "restore card {:?} ({}) in list {}", c.name, c.id, c.list_id),
+        }
+    }
+}
+
+impl PlankaClient {
+    /// Records the current state of a project so a later change can be undone
+    pub async fn snapshot_project(&self, project_id: &str) -> Result<Compensation, PlankaError> {
+        self.ensure_project_in_scope(project_id).await?;
+        let data = self.get_value(&format!("/api/projects/{project_id}")).await?;
+        let project: Project = serde_json::from_value(data["item"].clone())?;
+        debug!(project_id = %project_id, "Recorded project state");
+        Ok(Compensation::RestoreProject(project))
+    }
+
+    /// Records the current name and position of a board
+    pub async fn snapshot_board(&self, board_id: &str) -> Result<Compensation, PlankaError> {
+        self.ensure_board_in_scope(board_id).await?;
+        let data = self.fetch_board(board_id, true).await?;
+        debug!(board_id = %board_id, "Recorded board state");
+        Ok(Compensation::RestoreBoard(data.item))
+    }
+
+    /// Records the current name and position of a list
+    pub async fn snapshot_list(&self, list_id: &str) -> Result<Compensation, PlankaError> {
+        self.ensure_list_in_scope(list_id).await?;
+        let data = self.fetch_list(list_id).await?;
+        debug!(list_id = %list_id, "Recorded list state");
+        Ok(Compensation::RestoreList(data.item))
+    }
+
+    /// Records the current fields, list and position of a card
+    pub async fn snapshot_card(&self, card_id: &str) -> Result<Compensation, PlankaError> {
+        self.ensure_card_in_scope(card_id).await?;
+        let card = self.fetch_card(card_id).await?;
+        debug!(card_id = %card_id, "Recorded card state");
+        Ok(Compensation::RestoreCard(card))
+    }
+
+    /// Carries out a compensating action. Restoring writes back names,
+    /// descriptions, positions and card fields; a due date that was unset
+    /// before cannot be cleared again, so callers must reject such changes up front.
+    pub async fn compensate(&self, compensation: &Compensation) -> Result<(), PlankaError> {
+        info!(action = %compensation, "Running compensating action");
+        match compensation {
+            Compensation::DeleteProject { id, name } => self.delete_project(id, name).await,
+            Compensation::DeleteBoard { id } => {
+                self.ensure_board_in_scope(id).await?;
+                self.delete_board_in_scope(id).await
+            }
+            Compensation::DeleteList { id } => self.delete_list(id).await,
+            Compensation::DeleteCard { id } => self.delete_card(id).await,
+            Compensation::DeleteLabel { board_id, id } => {
+                self.ensure_board_in_scope(board_id).await?;
+                self.delete_label(board_id, id).await
+            }
+            Compensation::RestoreProject(project) => {
+                let background_gradient = match project.background_type.as_deref() {
+                    Some("image") => None,
+                    _ => Some(project.background_gradient.clone().unwrap_or_default()),
+                };
+                let options = UpdateProjectOptions {
+                    name: Some(project.name.clone()),
+                    description: Some(project.description.clone().unwrap_or_default()),
+                    background_gradient,
+                };
+                self.update_project(&project.id, options).await.map(|_| ())
+            }
+            Compensation::RestoreBoard(board) => {
+                let options = UpdateBoardOptions {
+                    name: Some(board.name.clone()),
+                    placement: board.position.map(Placement::At),
+                };
+                self.update_board(&board.id, options).await.map(|_| ())
+            }
+            Compensation::RestoreList(list) => {
+                let options = UpdateListOptions {
+                    name: Some(list.name.clone()),
+                    placement: list.position.map(Placement::At),
+                };
+                self.update_list(&list.id, options).await.map(|_| ())
+            }
+            Compensation::RestoreCard(card) => {
+                let options = UpdateCardOptions {
+                    name: Some(card.name.clone()),
+                    description: Some(card.description.clone().unwrap_or_default()),
+                    card_type: Some(card.card_type.clone()),
+                    due_date: card.due_date.clone(),
+                    is_due_completed: Some(card.is_due_completed.unwrap_or(false)),
+                    ..Default::default()
+                };
+                self.update_card(&card.id, options).await?;
+                let placement = card.position.map_or(Placement::Bottom, Placement::At);
+                self.move_card(&card.id, &card.list_id, placement).await.map(|_| ())
+            }
+        }
+    }
+}
@@ -0,0 +1,415 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use tracing::{debug, info, warn};
+
+use super::selection::SelectionArgs;
+use crate::mcp::types::{ToolCallResult, ToolContent};
+use crate::planka::client::PlankaError;
+use crate::planka::undo::Compensation;
+use crate::planka::PlankaClient;
+
+/// Tools a batch can run: each change they make can be compensated
+pub const BATCH_TOOLS: &[&str] = &[
+    "create_project",
+    "update_project",
+    "create_board",
+    "clone_board",
+    "update_board",
+    "create_list",
+    "update_list",
+    "create_card",
+    "duplicate_card",
+    "update_card",
+    "bulk_update_cards",
+    "move_card",
+    "bulk_move_cards",
+];
+
+/// One operation of a batch
+#[derive(Debug, Deserialize)]
+pub struct BatchStep {
+    /// Name later steps use to reference the result, in addition to the step index
+    pub id: Option<String>,
+    pub tool: String,
+    #[serde(default)]
+    pub args: Value,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StepOutcome {
+    pub index: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub tool: String,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Outcome of a batch: the steps that ran and, if one failed, the
+/// compensating actions taken in reverse order
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchReport {
+    pub ok: bool,
+    pub steps: Vec<StepOutcome>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rolled_back: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rollback_errors: Vec<String>,
+}
+
+/// Checks the steps before anything runs
+pub fn validate(steps: &[BatchStep]) -> Result<(), String> {
+    if steps.is_empty() {
+        return Err("operations must not be empty".to_string());
+    }
+    let mut ids: Vec<&str> = Vec::new();
+    for (i, step) in steps.iter().enumerate() {
+        if !BATCH_TOOLS.contains(&step.tool.as_str()) {
+            return Err(format!(
+                "step {i}: {} cannot run in a batch; supported tools: {}",
+                step.tool,
+                BATCH_TOOLS.join(", ")
+            ));
+        }
+        if !step.args.is_object() && !step.args.is_null() {
+            return Err(format!("step {i}: args must be an object"));
+        }
+        if step.args.get("instance").is_some() {
+            return Err(format!("step {i}: instance cannot be set per step, set it on the batch"));
+        }
+        if step.tool == "update_card" {
+            for field in ["board_id", "cover_attachment_id"] {
+                if step.args.get(field).is_some() {
+                    return Err(format!("step {i}: update_card with {field} cannot be undone"));
+                }
+            }
+        }
+        if let Some(id) = step.id.as_deref() {
+            if id.is_empty() || id.parse::<usize>().is_ok() || id.contains('.') {
+                return Err(format!("step {i}: id {id:?} must be a non-numeric name without dots"));
+            }
+            if ids.contains(&id) {
+                return Err(format!("step {i}: duplicate id {id:?}"));
+            }
+            ids.push(id);
+        }
+    }
+    Ok(())
+}
+
+/// Replaces every string of the form `$step.path` in `value` with the part
+/// of an earlier step's result at `path`, e.g. `$todo.id` or `$0.board.id`.
+/// `$$` at the start of a string stands for a literal `$`.
+pub fn resolve_refs(value: &Value, results: &HashMap<String, Value>) -> Result<Value, String> {
+    match value {
+        Value::String(s) if s.starts_with("$$") => Ok(Value::String(s[1..].to_string())),
+        Value::String(s) if s.starts_with('$') => {
+            let mut path = s[1..].split('.');
+            let step = path.next().unwrap_or_default();
+            let mut current = results
+                .get(step)
+                .ok_or_else(|| format!("{s}: no earlier step {step:?}"))?;
+            for key in path {
+                current = match current {
+                    Value::Object(map) => map.get(key),
+                    Value::Array(items) => key.parse::<usize>().ok().and_then(|i| items.get(i)),
+                    _ => None,
+                }
+                .ok_or_else(|| format!("{s}: the result of step {step:?} has no {key:?}"))?;
+            }
+            Ok(current.clone())
+        }
+        Value::Array(items) => items.iter().map(|v| resolve_refs(v, results)).collect(),
+        Value::Object(map) => map
+            .iter()
+            .map(|(k, v)| Ok((k.clone(), resolve_refs(v, results)?)))
+            .collect::<Result<serde_json::Map<_, _>, String>>()
+            .map(Value::Object),
+        other => Ok(other.clone()),
+    }
+}
+
+/// Records the state a step is about to change
+async fn snapshot(client: &PlankaClient, tool: &str, args: &Value) -> Result<Vec<Compensation>, PlankaError> {
+    let arg = |name: &str| args.get(name).and_then(Value::as_str);
+    let compensation = match tool {
+        "update_project" => match arg("project_id") {
+            Some(id) => client.snapshot_project(id).await?,
+            None => return Ok(Vec::new()),
+        },
+        "update_board" => match arg("board_id") {
+            Some(id) => client.snapshot_board(id).await?,
+            None => return Ok(Vec::new()),
+        },
+        "update_list" => match arg("list_id") {
+            Some(id) => client.snapshot_list(id).await?,
+            None => return Ok(Vec::new()),
+        },
+        "update_card" | "move_card" => match arg("card_id") {
+            Some(id) => client.snapshot_card(id).await?,
+            None => return Ok(Vec::new()),
+        },
+        "bulk_update_cards" | "bulk_move_cards" => {
+            let Ok(selection) = serde_json::from_value::<SelectionArgs>(args.clone()) else {
+                return Ok(Vec::new());
+            };
+            let Ok(selection) = selection.selection() else {
+                return Ok(Vec::new());
+            };
+            let mut snapshots = Vec::new();
+            for card_id in client.select_cards(&selection).await? {
+                snapshots.push(client.snapshot_card(&card_id).await?);
+            }
+            return Ok(snapshots);
+        }
+        _ => return Ok(Vec::new()),
+    };
+    Ok(vec![compensation])
+}
+
+/// Rejects a step whose change could not be undone from the recorded state
+fn check_undoable(tool: &str, args: &Value, snapshots: &[Compensation]) -> Result<(), String> {
+    let sets = |name: &str| args.get(name).is_some_and(|v| !v.is_null());
+    for snapshot in snapshots {
+        match snapshot {
+            Compensation::RestoreCard(card) if sets("due_date") && card.due_date.is_none() => {
+                return Err(format!(
+                    "{tool} sets a due date on card {} which has none; this cannot be undone",
+                    card.id
+                ));
+            }
+            Compensation::RestoreProject(project)
+                if sets("background_gradient") && project.background_type.as_deref() == Some("image") =>
+            {
+                return Err(format!(
+                    "{tool} replaces the background image of project {}; this cannot be undone",
+                    project.id
+                ));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// The actions deleting what a successful step created
+fn created(tool: &str, result: &Value) -> Vec<Compensation> {
+    let id = |item: &Value| item["id"].as_str().map(str::to_string);
+    let labels = |labels: &Value| {
+        labels
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|label| {
+                Some(Compensation::DeleteLabel {
+                    board_id: label["boardId"].as_str()?.to_string(),
+                    id: id(label)?,
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+    let mut compensations = Vec::new();
+    match tool {
+        "create_project" => {
+            if let (Some(id), Some(name)) = (id(result), result["name"].as_str()) {
+                compensations.push(Compensation::DeleteProject { id, name: name.to_string() });
+            }
+        }
+        "create_board" => compensations.extend(id(result).map(|id| Compensation::DeleteBoard { id })),
+        "clone_board" => {
+            compensations.extend(id(&result["board"]).map(|id| Compensation::DeleteBoard { id }));
+            compensations.extend(labels(&result["labels"]));
+        }
+        "create_list" => compensations.extend(id(result).map(|id| Compensation::DeleteList { id })),
+        "create_card" => compensations.extend(id(result).map(|id| Compensation::DeleteCard { id })),
+        "duplicate_card" => {
+            compensations.extend(id(&result["card"]).map(|id| Compensation::DeleteCard { id }));
+            compensations.extend(labels(&result["createdLabels"]));
+        }
+        _ => {}
+    }
+    compensations
+}
+
+/// The result of a tool as JSON, or as a string when it is not JSON
+fn result_value(result: &ToolCallResult) -> Value {
+    let text = result
+        .content
+        .first()
+        .map(|c| match c {
+            ToolContent::Text { text } => text.as_str(),
+            ToolContent::Resource { resource } => resource.text.as_str(),
+        })
+        .unwrap_or_default();
+    serde_json::from_str(text).unwrap_or_else(|_| Value::String(text.to_string()))
+}
+
+/// Runs the steps in order. When a step fails, the compensating actions of
+/// all changes made so far run in reverse order.
+pub async fn run(client: &PlankaClient, steps: Vec<BatchStep>) -> BatchReport {
+    info!(steps = steps.len(), "Running batch");
+    let mut report = BatchReport {
+        ok: true,
+        steps: Vec::with_capacity(steps.len()),
+        rolled_back: Vec::new(),
+        rollback_errors: Vec::new(),
+    };
+    let mut results: HashMap<String, Value> = HashMap::new();
+    let mut compensations: Vec<Compensation> = Vec::new();
+
+    for (index, step) in steps.into_iter().enumerate() {
+        let mut outcome = StepOutcome {
+            index,
+            id: step.id.clone(),
+            tool: step.tool.clone(),
+            ok: false,
+            result: None,
+            error: None,
+        };
+        match execute(client, &step, &results, &mut compensations).await {
+            Ok(result) => {
+                debug!(index, tool = %step.tool, "Batch step succeeded");
+                results.insert(index.to_string(), result.clone());
+                if let Some(id) = step.id {
+                    results.insert(id, result.clone());
+                }
+                outcome.ok = true;
+                outcome.result = Some(result);
+                report.steps.push(outcome);
+            }
+            Err(e) => {
+                warn!(index, tool = %step.tool, error = %e, "Batch step failed, rolling back");
+                outcome.error = Some(e);
+                report.steps.push(outcome);
+                report.ok = false;
+                break;
+            }
+        }
+    }
+
+    if !report.ok {
+        while let Some(compensation) = compensations.pop() {
+            match client.compensate(&compensation).await {
+                Ok(()) => report.rolled_back.push(compensation.to_string()),
+                Err(e) => {
+                    warn!(action = %compensation, error = %e, "Compensating action failed");
+                    report.rollback_errors.push(format!("{compensation}: {e}"));
+                }
+            }
+        }
+    }
+    info!(
+        ok = report.ok,
+        steps = report.steps.len(),
+        rolled_back = report.rolled_back.len(),
+        "Batch finished"
+    );
+    report
+}
+
+/// Runs one step, recording how to undo it
+async fn execute(
+    client: &PlankaClient,
+    step: &BatchStep,
+    results: &HashMap<String, Value>,
+    compensations: &mut Vec<Compensation>,
+) -> Result<Value, String> {
+    let args = resolve_refs(&step.args, results)?;
+    let snapshots = snapshot(client, &step.tool, &args)
+        .await
+        .map_err(|e| format!("Failed to record the state before {}: {e}", step.tool))?;
+    check_undoable(&step.tool, &args, &snapshots)?;
+    compensations.extend(snapshots);
+
+    let result = Box::pin(super::dispatch(client, &step.tool, Some(args))).await;
+    let value = result_value(&result);
+    if result.is_error == Some(true) {
+        return Err(value.as_str().map_or_else(|| value.to_string(), str::to_string));
+    }
+    compensations.extend(created(&step.tool, &value));
+    // Bulk tools succeed as a whole even when some cards fail
+    if value["failed"].as_u64().is_some_and(|failed| failed > 0) {
+        return Err(format!("{} failed for {} card(s)", step.tool, value["failed"]));
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_resolve_refs_reads_earlier_results() {
+        let results = HashMap::from([
+            ("0".to_string(), json!({"board": {"id": "5"}, "lists": [{"id": "6"}]})),
+            ("todo".to_string(), json!({"id": "7", "name": "Todo"})),
+        ]);
+        let args = json!({
+            "list_id": "$todo.id",
+            "board_id": "$0.board.id",
+            "after": "$0.lists.0.id",
+            "name": "$$5 budget",
+            "card_ids": ["$todo.id", "8"],
+            "position": 1.5
+        });
+        assert_eq!(
+            resolve_refs(&args, &results).unwrap(),
+            json!({
+                "list_id": "7",
+                "board_id": "5",
+                "after": "6",
+                "name": "$5 budget",
+                "card_ids": ["7", "8"],
+                "position": 1.5
+            })
+        );
+        assert!(resolve_refs(&json!("$done.id"), &results).is_err());
+        assert!(resolve_refs(&json!("$todo.position"), &results).is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_unsupported_tools_and_duplicate_ids() {
+        let step = |id: Option<&str>, tool: &str| BatchStep {
+            id: id.map(str::to_string),
+            tool: tool.to_string(),
+            args: json!({}),
+        };
+        assert!(validate(&[step(Some("a"), "create_list"), step(None, "move_card")]).is_ok());
+        assert!(validate(&[step(None, "delete_card")]).is_err());
+        assert!(validate(&[step(Some("a"), "create_list"), step(Some("a"), "create_card")]).is_err());
+        assert!(validate(&[step(Some("1"), "create_list")]).is_err());
+        assert!(validate(&[]).is_err());
+
+        let with_args = |tool: &str, args: Value| BatchStep { id: None, tool: tool.to_string(), args };
+        assert!(validate(&[with_args("create_list", json!({"board_id": "1", "instance": "b"}))]).is_err());
+        assert!(validate(&[with_args("update_card", json!({"card_id": "1", "board_id": "2"}))]).is_err());
+    }
+
+    #[test]
+    fn test_created_items_include_labels_added_to_the_target_board() {
+        let result = json!({
+            "card": {"id": "10"},
+            "createdLabels": [{"id": "20", "boardId": "2", "name": "bug", "color": "berry-red"}]
+        });
+        let compensations: Vec<String> = created("duplicate_card", &result).iter().map(ToString::to_string).collect();
+        assert_eq!(compensations, ["delete card 10", "delete label 20 on board 2"]);
+    }
+
+    #[test]
+    fn test_setting_a_missing_due_date_cannot_be_undone() {
+        let card: crate::planka::types::Card = serde_json::from_value(json!({
+            "id": "10", "listId": "1", "name": "Spec", "type": "project"
+        }))
+        .unwrap();
+        let snapshots = [Compensation::RestoreCard(card)];
+        assert!(check_undoable("update_card", &json!({"due_date": "2026-01-01"}), &snapshots).is_err());
+        assert!(check_undoable("update_card", &json!({"name": "Renamed"}), &snapshots).is_ok());
+    }
+}
@@ -0,0 +1,223 @@
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::fmt::Write;
+
+use super::format::{render, OutputFormat};
+use super::overview::lists_in_order;
+use crate::planka::types::{BoardResponse, Card, CardType, List};
+
+/// Document formats of `export_board`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    /// Lists as headings and cards as items with their details
+    #[default]
+    Markdown,
+    /// The board response as returned by Planka
+    Json,
+    /// One row per card
+    Csv,
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "md",
+            ExportFormat::Json => "json",
+            ExportFormat::Csv => "csv",
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "text/markdown",
+            ExportFormat::Json => "application/json",
+            ExportFormat::Csv => "text/csv",
+        }
+    }
+}
+
+/// Columns of the CSV export
+const CSV_COLUMNS: &[&str] = &[
+    "id",
+    "list",
+    "name",
+    "type",
+    "description",
+    "labels",
+    "members",
+    "dueDate",
+    "isDueDateCompleted",
+    "tasksCompleted",
+    "tasksTotal",
+];
+
+/// A card with the names of its list, labels and members and its task progress
+struct CardDetails<'a> {
+    card: &'a Card,
+    labels: Vec<&'a str>,
+    members: Vec<&'a str>,
+    tasks_completed: usize,
+    tasks_total: usize,
+}
+
+/// Lists in board order, as in the overview, each with the details of its cards
+fn lists_with_cards(data: &BoardResponse) -> Vec<(&List, Vec<CardDetails<'_>>)> {
+    let included = &data.included;
+    lists_in_order(data)
+        .into_iter()
+        .map(|(list, cards)| {
+            let cards = cards
+                .into_iter()
+                .map(|card| {
+                    let labels = included
+                        .card_labels
+                        .iter()
+                        .filter(|cl| cl.card_id == card.id)
+                        .filter_map(|cl| included.labels.iter().find(|l| l.id == cl.label_id))
+                        .map(|l| l.name.as_deref().unwrap_or(&l.color))
+                        .collect();
+                    let members = included
+                        .card_memberships
+                        .iter()
+                        .filter(|m| m.card_id == card.id)
+                        .map(|m| {
+                            included
+                                .users
+                                .iter()
+                                .find(|u| u.id == m.user_id)
+                                .map_or(m.user_id.as_str(), |u| u.name.as_str())
+                        })
+                        .collect();
+                    let tasks: Vec<_> = included
+                        .tasks
+                        .iter()
+                        .filter(|t| {
+                            included
+                                .task_lists
+                                .iter()
+                                .any(|tl| tl.id == t.task_list_id && tl.card_id == card.id)
+                        })
+                        .collect();
+                    CardDetails {
+                        card,
+                        labels,
+                        members,
+                        tasks_completed: tasks.iter().filter(|t| t.is_completed).count(),
+                        tasks_total: tasks.len(),
+                    }
+                })
+                .collect();
+            (list, cards)
+        })
+        .collect()
+}
+
+/// Markdown document with one heading per list and one item per card
+pub fn to_markdown(data: &BoardResponse) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "# {}", data.item.name);
+    for (list, cards) in lists_with_cards(data) {
+        let _ = writeln!(out, "\n## {} ({} cards)\n", list.name, cards.len());
+        if cards.is_empty() {
+            let _ = writeln!(out, "_No cards_");
+        }
+        for details in cards {
+            let card = details.card;
+            let _ = write!(out, "- **{}**", card.name);
+            if matches!(card.card_type, CardType::Story) {
+                let _ = write!(out, " [story]");
+            }
+            if !details.labels.is_empty() {
+                let _ = write!(out, " · labels: {}", details.labels.join(", "));
+            }
+            if !details.members.is_empty() {
+                let _ = write!(out, " · members: {}", details.members.join(", "));
+            }
+            if let Some(due) = &card.due_date {
+                let done = if card.is_due_completed == Some(true) { " (done)" } else { "" };
+                let _ = write!(out, " · due {due}{done}");
+            }
+            if details.tasks_total > 0 {
+                let _ = write!(out, " · tasks {}/{}", details.tasks_completed, details.tasks_total);
+            }
+            out.push('\n');
+            for line in card.description.as_deref().unwrap_or_default().lines() {
+                let _ = writeln!(out, "  {line}");
+            }
+        }
+    }
+    out
+}
+
+/// CSV document with one row per card, in board order
+pub fn to_csv(data: &BoardResponse) -> Result<String, String> {
+    let rows: Vec<Value> = lists_with_cards(data)
+        .into_iter()
+        .flat_map(|(list, cards)| {
+            cards.into_iter().map(move |details| {
+                let card = details.card;
+                json!({
+                    "id": card.id,
+                    "list": list.name,
+                    "name": card.name,
+                    "type": card.card_type,
+                    "description": card.description,
+                    "labels": details.labels.join("; "),
+                    "members": details.members.join("; "),
+                    "dueDate": card.due_date,
+                    "isDueDateCompleted": card.due_date.as_ref().and(card.is_due_completed),
+                    "tasksCompleted": details.tasks_completed,
+                    "tasksTotal": details.tasks_total,
+                })
+            })
+        })
+        .collect();
+    render(&rows, OutputFormat::Csv, None, CSV_COLUMNS, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::planka::test_support::BoardBuilder;
+
+    fn board() -> BoardResponse {
+        let details = json!({"description": "First line\nSecond line", "dueDate": "2026-01-01", "isDueDateCompleted": true});
+        BoardBuilder::new("Sprint")
+            .list("b", "Done", Some(2.0))
+            .list("a", "Todo", Some(1.0))
+            .card_with("10", "a", "Spec", Some(1.0), details)
+            .label("50", "bug", "berry-red", None)
+            .card_label("70", "10", "50")
+            .included("cardMemberships", json!({"id": "80", "cardId": "10", "userId": "2"}))
+            .included("users", json!({"id": "2", "name": "Bob"}))
+            .included("taskLists", json!({"id": "90", "cardId": "10", "name": "Checklist"}))
+            .included("tasks", json!({"id": "91", "taskListId": "90", "name": "a", "isCompleted": true}))
+            .included("tasks", json!({"id": "92", "taskListId": "90", "name": "b"}))
+            .build()
+    }
+
+    #[test]
+    fn test_markdown_export_lists_card_details() {
+        let markdown = to_markdown(&board());
+        assert_eq!(
+            markdown,
+            "# Sprint\n\n## Todo (1 cards)\n\n\
+             - **Spec** · labels: bug · members: Bob · due 2026-01-01 (done) · tasks 1/2\n  First line\n  Second line\n\n\
+             ## Done (0 cards)\n\n_No cards_\n"
+        );
+    }
+
+    #[test]
+    fn test_csv_export_has_one_row_per_card() {
+        let csv = to_csv(&board()).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some(CSV_COLUMNS.join(",").as_str()));
+        assert_eq!(
+            lines.next(),
+            Some("10,Todo,Spec,project,\"First line")
+        );
+        assert_eq!(lines.nth(1), None);
+        assert!(csv.contains("Second line\",bug,Bob,2026-01-01,true,1,2\n"));
+    }
+}
@@ -0,0 +1,229 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeSet;
+
+use crate::mcp::types::Tool;
+
+/// Output formats of the listing tools
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputFormat {
+    /// Pretty-printed JSON with every field unless `fields` is given
+    Json,
+    /// Minified JSON without null values
+    #[default]
+    CompactJson,
+    MarkdownTable,
+    Csv,
+}
+
+/// Adds the `format` and `fields` arguments to a listing tool's input schema
+pub fn with_format_args(mut tool: Tool, default_fields: &[&str]) -> Tool {
+    if let Some(properties) = tool.input_schema["properties"].as_object_mut() {
+        properties.insert(
+            "format".to_string(),
+            json!({
+                "type": "string",
+                "enum": ["json", "compact_json", "markdown_table", "csv"],
+                "description": "Output format (default: compact_json)"
+            }),
+        );
+        properties.insert(
+            "fields".to_string(),
+            json!({
+                "type": "array",
+                "items": {"type": "string"},
+                "description": format!(
+                    "Fields to include, e.g. [\"id\", \"name\"] (default: {}; all fields with format json)",
+                    default_fields.join(", ")
+                )
+            }),
+        );
+    }
+    tool
+}
+
+/// Renders items in the requested format, keeping only the selected fields.
+///
+/// Without explicit `fields`, `json` keeps every field while the compact
+/// formats fall back to `default_fields`. When `next_cursor` is set, JSON
+/// output becomes `{"items": [...], "nextCursor": "..."}` and the text formats
+/// end with a `nextCursor: ...` line.
+pub fn render<T: Serialize>(
+    items: &[T],
+    format: OutputFormat,
+    fields: Option<&[String]>,
+    default_fields: &[&str],
+    next_cursor: Option<&str>,
+) -> Result<String, String> {
+    let rows: Vec<Map<String, Value>> = items
+        .iter()
+        .map(|item| match serde_json::to_value(item) {
+            Ok(Value::Object(map)) => Ok(map),
+            Ok(_) => Err("Items must serialize to JSON objects".to_string()),
+            Err(e) => Err(format!("Failed to serialize items: {e}")),
+        })
+        .collect::<Result<_, _>>()?;
+
+    let columns: Option<Vec<String>> = match fields {
+        Some(fields) => Some(resolve_fields(&rows, fields)?),
+        None if format == OutputFormat::Json => None,
+        None => Some(default_fields.iter().map(|f| f.to_string()).collect()),
+    };
+    let rows: Vec<Map<String, Value>> = match &columns {
+        Some(columns) => rows
+            .into_iter()
+            .map(|mut row| {
+                columns
+                    .iter()
+                    .map(|c| (c.clone(), row.remove(c).unwrap_or(Value::Null)))
+                    .collect()
+            })
+            .collect(),
+        None => rows,
+    };
+
+    let paged = |rows: Vec<Map<String, Value>>| match next_cursor {
+        Some(cursor) => json!({"items": rows, "nextCursor": cursor}),
+        None => json!(rows),
+    };
+    let mut output = match format {
+        OutputFormat::Json => serde_json::to_string_pretty(&paged(rows)).unwrap_or_default(),
+        OutputFormat::CompactJson => {
+            let rows: Vec<Map<String, Value>> = rows
+                .into_iter()
+                .map(|row| row.into_iter().filter(|(_, v)| !v.is_null()).collect())
+                .collect();
+            serde_json::to_string(&paged(rows)).unwrap_or_default()
+        }
+        OutputFormat::MarkdownTable => {
+            let columns = columns.unwrap_or_default();
+            let mut out = format!("| {} |\n|{}\n", columns.join(" | "), "---|".repeat(columns.len()));
+            for row in &rows {
+                let cells: Vec<String> = columns
+                    .iter()
+                    .map(|c| cell(&row[c]).replace('|', "\\|").replace(['\r', '\n'], " "))
+                    .collect();
+                out.push_str(&format!("| {} |\n", cells.join(" | ")));
+            }
+            out
+        }
+        OutputFormat::Csv => {
+            let columns = columns.unwrap_or_default();
+            let mut out = csv_line(columns.iter().cloned());
+            for row in &rows {
+                out.push_str(&csv_line(columns.iter().map(|c| cell(&row[c]))));
+            }
+            out
+        }
+    };
+    if let (Some(cursor), OutputFormat::MarkdownTable | OutputFormat::Csv) = (next_cursor, format) {
+        output.push_str(&format!("\nnextCursor: {cursor}\n"));
+    }
+    Ok(output)
+}
+
+/// Maps requested field names (camelCase or snake_case) onto the item keys
+fn resolve_fields(rows: &[Map<String, Value>], fields: &[String]) -> Result<Vec<String>, String> {
+    let available: BTreeSet<&str> = rows.iter().flat_map(|r| r.keys().map(String::as_str)).collect();
+    let mut columns = Vec::with_capacity(fields.len());
+    let mut unknown = Vec::new();
+    for field in fields {
+        let camel = to_camel_case(field);
+        if available.is_empty() || available.contains(field.as_str()) {
+            columns.push(field.clone());
+        } else if available.contains(camel.as_str()) {
+            columns.push(camel);
+        } else {
+            unknown.push(field.as_str());
+        }
+    }
+    if !unknown.is_empty() {
+        let available: Vec<&str> = available.into_iter().collect();
+        return Err(format!(
+            "Unknown field(s): {}. Available fields: {}",
+            unknown.join(", "),
+            available.join(", ")
+        ));
+    }
+    Ok(columns)
+}
+
+fn to_camel_case(field: &str) -> String {
+    let mut out = String::with_capacity(field.len());
+    let mut upper = false;
+    for ch in field.chars() {
+        if ch == '_' {
+            upper = true;
+        } else if upper {
+            out.extend(ch.to_uppercase());
+            upper = false;
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn csv_line(cells: impl Iterator<Item = String>) -> String {
+    let cells: Vec<String> = cells
+        .map(|c| {
+            if c.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", c.replace('"', "\"\""))
+            } else {
+                c
+            }
+        })
+        .collect();
+    format!("{}\n", cells.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items() -> Vec<Value> {
+        vec![
+            json!({"id": "1", "name": "Plain", "listId": "9", "dueDate": null}),
+            json!({"id": "2", "name": "Comma, \"quoted\" | piped", "listId": "9", "dueDate": "2026-01-01"}),
+        ]
+    }
+
+    #[test]
+    fn test_compact_json_uses_default_fields_and_drops_nulls() {
+        let out = render(&items(), OutputFormat::CompactJson, None, &["id", "dueDate"], None).unwrap();
+        assert_eq!(out, r#"[{"id":"1"},{"id":"2","dueDate":"2026-01-01"}]"#);
+    }
+
+    #[test]
+    fn test_csv_and_markdown_escaping() {
+        let fields = vec!["id".to_string(), "name".to_string()];
+        let csv = render(&items(), OutputFormat::Csv, Some(&fields), &[], None).unwrap();
+        assert_eq!(csv, "id,name\n1,Plain\n2,\"Comma, \"\"quoted\"\" | piped\"\n");
+
+        let table = render(&items(), OutputFormat::MarkdownTable, Some(&fields), &[], None).unwrap();
+        assert_eq!(table, "| id | name |\n|---|---|\n| 1 | Plain |\n| 2 | Comma, \"quoted\" \\| piped |\n");
+    }
+
+    #[test]
+    fn test_fields_accept_snake_case_and_reject_unknown() {
+        let fields = vec!["list_id".to_string()];
+        let out = render(&items(), OutputFormat::Csv, Some(&fields), &[], None).unwrap();
+        assert_eq!(out, "listId\n9\n9\n");
+
+        let out = render(&items(), OutputFormat::CompactJson, Some(&fields), &[], Some("o2")).unwrap();
+        assert_eq!(out, r#"{"items":[{"listId":"9"},{"listId":"9"}],"nextCursor":"o2"}"#);
+
+        let fields = vec!["nope".to_string()];
+        let err = render(&items(), OutputFormat::Json, Some(&fields), &[], None).unwrap_err();
+        assert!(err.starts_with("Unknown field(s): nope."));
+    }
+}
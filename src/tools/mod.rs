@@ -3,7 +3,31 @@ use serde_json::{json, Value};
 use tracing::{debug, error, info, trace, warn};
 
 use crate::mcp::types::{Tool, ToolAnnotations, ToolCallResult};
-use crate::planka::PlankaClient;
+use crate::planka::backup::ProjectBackup;
+use crate::planka::copy::{CardPart, CloneBoardOptions, DuplicateCardOptions};
+use crate::planka::import::{self, CsvColumns, ImportFormat};
+use crate::planka::position::Placement;
+use crate::planka::spec::{ApplyOptions, BoardSpec};
+use crate::planka::{ClientRegistry, PlankaClient};
+
+mod batch;
+mod export;
+mod format;
+mod overview;
+mod page;
+mod selection;
+
+use export::ExportFormat;
+use format::{render, with_format_args, OutputFormat};
+use overview::BoardOverview;
+use page::{paginate, with_page_args, PageOrder};
+use selection::{with_selection_args, SelectionArgs};
+
+/// Fields returned by the listing tools unless `fields` is given
+const PROJECT_FIELDS: &[&str] = &["id", "name"];
+const BOARD_FIELDS: &[&str] = &["id", "name", "projectId", "position"];
+const LIST_FIELDS: &[&str] = &["id", "name", "position"];
+const CARD_FIELDS: &[&str] = &["id", "name", "type", "listId", "position", "dueDate"];
 
 /// Creates annotations enabling programmatic tool calling
 fn programmatic_annotations() -> Option<ToolAnnotations> {
@@ -12,28 +36,208 @@ fn programmatic_annotations() -> Option<ToolAnnotations> {
     })
 }
 
+/// Adds the optional `instance` argument to a tool's input schema
+fn with_instance_arg(mut tool: Tool) -> Tool {
+    if let Some(properties) = tool.input_schema["properties"].as_object_mut() {
+        properties.insert(
+            "instance".to_string(),
+            json!({
+                "type": "string",
+                "description": "Planka instance to use (optional, see list_instances)"
+            }),
+        );
+    }
+    tool
+}
+
 /// Returns the list of available tools
 pub fn list_tools() -> Vec<Tool> {
+    let mut tools: Vec<Tool> = planka_tools().into_iter().map(with_instance_arg).collect();
+    tools.push(Tool {
+        name: "list_instances".to_string(),
+        description: "List the configured Planka instances and which one is the default".to_string(),
+        input_schema: json!({
+            "type": "object",
+            "properties": {},
+            "required": []
+        }),
+        annotations: programmatic_annotations(),
+    });
+    tools
+}
+
+/// Tools that operate on a single Planka instance
+fn planka_tools() -> Vec<Tool> {
     vec![
+        with_page_args(with_format_args(
+            Tool {
+                name: "list_projects".to_string(),
+                description: "List all Planka projects".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {},
+                    "required": []
+                }),
+                annotations: programmatic_annotations(),
+            },
+            PROJECT_FIELDS,
+        )),
+        with_page_args(with_format_args(
+            Tool {
+                name: "list_boards".to_string(),
+                description: "List all boards in a project".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "project_id": {
+                            "type": "string",
+                            "description": "The project ID"
+                        },
+                        "refresh": {
+                            "type": "boolean",
+                            "description": "Bypass the snapshot cache and fetch fresh data (default: false)"
+                        }
+                    },
+                    "required": ["project_id"]
+                }),
+                annotations: programmatic_annotations(),
+            },
+            BOARD_FIELDS,
+        )),
+        with_page_args(with_format_args(
+            Tool {
+                name: "list_lists".to_string(),
+                description: "List all lists (columns) on a board".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "board_id": {
+                            "type": "string",
+                            "description": "The board ID"
+                        },
+                        "refresh": {
+                            "type": "boolean",
+                            "description": "Bypass the snapshot cache and fetch fresh data (default: false)"
+                        }
+                    },
+                    "required": ["board_id"]
+                }),
+                annotations: programmatic_annotations(),
+            },
+            LIST_FIELDS,
+        )),
+        with_page_args(with_format_args(
+            Tool {
+                name: "list_cards".to_string(),
+                description: "List all cards on a board".to_string(),
+                input_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        "board_id": {
+                            "type": "string",
+                            "description": "The board ID"
+                        },
+                        "refresh": {
+                            "type": "boolean",
+                            "description": "Bypass the snapshot cache and fetch fresh data (default: false)"
+                        }
+                    },
+                    "required": ["board_id"]
+                }),
+                annotations: programmatic_annotations(),
+            },
+            CARD_FIELDS,
+        )),
         Tool {
-            name: "list_projects".to_string(),
-            description: "List all Planka projects".to_string(),
+            name: "get_board".to_string(),
+            description: "Get an overview of a board: its lists in order, each with its cards in order and a card count".to_string(),
             input_schema: json!({
                 "type": "object",
-                "properties": {},
-                "required": []
+                "properties": {
+                    "board_id": {
+                        "type": "string",
+                        "description": "The board ID"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "json"],
+                        "description": "Output format (default: markdown)"
+                    },
+                    "refresh": {
+                        "type": "boolean",
+                        "description": "Bypass the snapshot cache and fetch fresh data (default: false)"
+                    }
+                },
+                "required": ["board_id"]
+            }),
+            annotations: programmatic_annotations(),
+        },
+        Tool {
+            name: "export_board".to_string(),
+            description: "Export a board as a document for people without Planka access: Markdown (lists as headings, cards with labels, members, due dates and task progress), the full JSON board data, or a CSV of cards. Returned as an embedded resource".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "board_id": {
+                        "type": "string",
+                        "description": "The board ID"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["markdown", "json", "csv"],
+                        "description": "Document format (default: markdown)"
+                    }
+                },
+                "required": ["board_id"]
+            }),
+            annotations: programmatic_annotations(),
+        },
+        Tool {
+            name: "create_project".to_string(),
+            description: "Create a new project".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "string",
+                        "description": "The project name"
+                    },
+                    "type": {
+                        "type": "string",
+                        "enum": ["private", "shared"],
+                        "description": "Private projects are owned by the creator, shared ones by all admins",
+                        "default": "private"
+                    },
+                    "description": {
+                        "type": "string",
+                        "description": "Optional project description"
+                    }
+                },
+                "required": ["name"]
             }),
             annotations: programmatic_annotations(),
         },
         Tool {
-            name: "list_boards".to_string(),
-            description: "List all boards in a project".to_string(),
+            name: "update_project".to_string(),
+            description: "Rename a project or change its description or background".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "project_id": {
                         "type": "string",
-                        "description": "The project ID"
+                        "description": "The project ID to update"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "New project name (optional)"
+                    },
+                    "description": {
+                        "type": "string",
+                        "description": "New project description (optional)"
+                    },
+                    "background_gradient": {
+                        "type": "string",
+                        "description": "Background gradient name, e.g. \"ocean-dive\", or \"\" to remove the background (optional)"
                     }
                 },
                 "required": ["project_id"]
@@ -41,35 +245,98 @@ pub fn list_tools() -> Vec<Tool> {
             annotations: programmatic_annotations(),
         },
         Tool {
-            name: "list_lists".to_string(),
-            description: "List all lists (columns) on a board".to_string(),
+            name: "delete_project".to_string(),
+            description: "Delete a project with all its boards, lists and cards. Requires confirm set to the project name".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
-                    "board_id": {
+                    "project_id": {
                         "type": "string",
-                        "description": "The board ID"
+                        "description": "The project ID to delete"
+                    },
+                    "confirm": {
+                        "type": "string",
+                        "description": "The exact name of the project, to confirm the deletion"
                     }
                 },
-                "required": ["board_id"]
+                "required": ["project_id", "confirm"]
+            }),
+            // Not enabled for programmatic calling (destructive operation)
+            annotations: None,
+        },
+        Tool {
+            name: "backup_project".to_string(),
+            description: "Back up a project: every board with its lists, cards, labels, task lists, tasks, attachment metadata and card comments, as a JSON document for restore_project. Returned as an embedded resource".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "project_id": {
+                        "type": "string",
+                        "description": "The project ID to back up"
+                    }
+                },
+                "required": ["project_id"]
             }),
             annotations: programmatic_annotations(),
         },
         Tool {
-            name: "list_cards".to_string(),
-            description: "List all cards on a board".to_string(),
+            name: "restore_project".to_string(),
+            description: "Recreate a project from a backup_project document under new ids, with its boards, lists, labels, cards, task lists and comments. Members and attachment files are not restored. If a step fails, the partial project is deleted".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
-                    "board_id": {
+                    "backup": {
+                        "oneOf": [{"type": "string"}, {"type": "object"}],
+                        "description": "The backup document, as a JSON string or an object"
+                    },
+                    "name": {
                         "type": "string",
-                        "description": "The board ID"
+                        "description": "Name of the restored project (default: the name in the backup)"
                     }
                 },
-                "required": ["board_id"]
+                "required": ["backup"]
+            }),
+            annotations: programmatic_annotations(),
+        },
+        Tool {
+            name: "add_project_manager".to_string(),
+            description: "Make a user a manager of a project".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "project_id": {
+                        "type": "string",
+                        "description": "The project ID"
+                    },
+                    "user_id": {
+                        "type": "string",
+                        "description": "The user ID to add as manager"
+                    }
+                },
+                "required": ["project_id", "user_id"]
             }),
             annotations: programmatic_annotations(),
         },
+        Tool {
+            name: "remove_project_manager".to_string(),
+            description: "Remove a user from the managers of a project".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "project_id": {
+                        "type": "string",
+                        "description": "The project ID"
+                    },
+                    "user_id": {
+                        "type": "string",
+                        "description": "The user ID to remove"
+                    }
+                },
+                "required": ["project_id", "user_id"]
+            }),
+            // Not enabled for programmatic calling (destructive operation)
+            annotations: None,
+        },
         Tool {
             name: "create_board".to_string(),
             description: "Create a new board in a project".to_string(),
@@ -90,247 +357,1239 @@ pub fn list_tools() -> Vec<Tool> {
             annotations: programmatic_annotations(),
         },
         Tool {
-            name: "create_list".to_string(),
-            description: "Create a new list (column) on a board".to_string(),
+            name: "clone_board".to_string(),
+            description: "Create a new board with the lists of an existing board, optionally with its labels and cards; the new board is deleted again if any step fails".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
                     "board_id": {
                         "type": "string",
-                        "description": "The board ID to create the list on"
+                        "description": "The board ID to use as template"
+                    },
+                    "project_id": {
+                        "type": "string",
+                        "description": "The project ID receiving the new board"
                     },
                     "name": {
                         "type": "string",
-                        "description": "The list name"
+                        "description": "Name of the new board"
+                    },
+                    "include_labels": {
+                        "type": "boolean",
+                        "description": "Recreate the board's labels (default: true)"
+                    },
+                    "include_cards": {
+                        "type": "boolean",
+                        "description": "Recreate the cards with their description, due date, labels and task lists (default: false)"
                     }
                 },
-                "required": ["board_id", "name"]
+                "required": ["board_id", "project_id", "name"]
             }),
             annotations: programmatic_annotations(),
         },
         Tool {
-            name: "create_card".to_string(),
-            description: "Create a new card in a list".to_string(),
+            name: "update_board".to_string(),
+            description: "Rename a board and/or move it to another position in its project".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
-                    "list_id": {
-                        "type": "string",
-                        "description": "The list ID to create the card in"
-                    },
-                    "type": {
+                    "board_id": {
                         "type": "string",
-                        "enum": ["project", "story"],
-                        "description": "Type of the card (project or story)",
-                        "default": "project"
+                        "description": "The board ID to update"
                     },
                     "name": {
                         "type": "string",
-                        "description": "The card title"
+                        "description": "New board name (optional)"
                     },
-                    "description": {
-                        "type": "string",
-                        "description": "Optional card description"
+                    "position": {
+                        "oneOf": [
+                            {"type": "string", "enum": ["top", "bottom"]},
+                            {"type": "number"}
+                        ],
+                        "description": "\"top\" (first), \"bottom\" (last) or a raw position value (optional)"
                     },
-                    "due_date": {
+                    "before_board_id": {
                         "type": "string",
-                        "format": "date-time",
-                        "description": "Optional due date (ISO 8601 format)"
+                        "description": "Place the board directly before this board (optional)"
                     },
-                    "is_due_completed": {
-                        "type": "boolean",
-                        "description": "Whether the due date is completed"
+                    "after_board_id": {
+                        "type": "string",
+                        "description": "Place the board directly after this board (optional)"
                     }
                 },
-                "required": ["list_id", "name"]
+                "required": ["board_id"]
             }),
             annotations: programmatic_annotations(),
         },
         Tool {
-            name: "update_card".to_string(),
-            description: "Update a card's properties (name, description, type, due date, etc.)".to_string(),
+            name: "delete_board".to_string(),
+            description: "Delete a board with all its lists and cards. Requires confirm set to the board name".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
-                    "card_id": {
+                    "board_id": {
                         "type": "string",
-                        "description": "The card ID to update"
+                        "description": "The board ID to delete"
                     },
-                    "name": {
+                    "confirm": {
                         "type": "string",
-                        "description": "New card title (optional)"
-                    },
-                    "description": {
+                        "description": "The exact name of the board, to confirm the deletion"
+                    }
+                },
+                "required": ["board_id", "confirm"]
+            }),
+            // Not enabled for programmatic calling (destructive operation)
+            annotations: None,
+        },
+        Tool {
+            name: "list_board_members".to_string(),
+            description: "List the members of a board with their role (editor or viewer)".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "board_id": {
                         "type": "string",
-                        "description": "New card description (optional)"
-                    },
-                    "type": {
+                        "description": "The board ID"
+                    }
+                },
+                "required": ["board_id"]
+            }),
+            annotations: programmatic_annotations(),
+        },
+        Tool {
+            name: "add_board_member".to_string(),
+            description: "Give a user access to a board".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "board_id": {
                         "type": "string",
-                        "enum": ["project", "story"],
-                        "description": "Card type (optional)"
+                        "description": "The board ID"
                     },
-                    "due_date": {
+                    "user_id": {
                         "type": "string",
-                        "format": "date-time",
-                        "description": "Due date in ISO 8601 format (optional)"
-                    },
-                    "is_due_completed": {
-                        "type": "boolean",
-                        "description": "Whether the due date is completed (optional)"
+                        "description": "The user ID to add"
                     },
-                    "board_id": {
+                    "role": {
                         "type": "string",
-                        "description": "Move card to different board (optional)"
+                        "enum": ["editor", "viewer"],
+                        "description": "Role of the member",
+                        "default": "editor"
                     },
-                    "cover_attachment_id": {
-                        "type": "string",
-                        "description": "Set cover image attachment ID (optional)"
+                    "can_comment": {
+                        "type": "boolean",
+                        "description": "Whether a viewer may comment (optional)"
                     }
                 },
-                "required": ["card_id"]
+                "required": ["board_id", "user_id"]
             }),
             annotations: programmatic_annotations(),
         },
         Tool {
-            name: "move_card".to_string(),
-            description: "Move a card to a different list".to_string(),
+            name: "update_board_member".to_string(),
+            description: "Change the role of a board member".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
-                    "card_id": {
+                    "board_id": {
                         "type": "string",
-                        "description": "The card ID to move"
+                        "description": "The board ID"
                     },
-                    "list_id": {
+                    "user_id": {
                         "type": "string",
-                        "description": "The target list ID"
+                        "description": "The member's user ID"
                     },
-                    "position": {
-                        "type": "number",
-                        "description": "Position in the list (optional)"
+                    "role": {
+                        "type": "string",
+                        "enum": ["editor", "viewer"],
+                        "description": "New role (optional)"
+                    },
+                    "can_comment": {
+                        "type": "boolean",
+                        "description": "Whether a viewer may comment (optional)"
                     }
                 },
-                "required": ["card_id", "list_id"]
+                "required": ["board_id", "user_id"]
             }),
             annotations: programmatic_annotations(),
         },
         Tool {
-            name: "delete_card".to_string(),
-            description: "Delete a card".to_string(),
+            name: "remove_board_member".to_string(),
+            description: "Revoke a user's access to a board".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
-                    "card_id": {
+                    "board_id": {
                         "type": "string",
-                        "description": "The card ID to delete"
+                        "description": "The board ID"
+                    },
+                    "user_id": {
+                        "type": "string",
+                        "description": "The member's user ID"
                     }
                 },
-                "required": ["card_id"]
+                "required": ["board_id", "user_id"]
             }),
             // Not enabled for programmatic calling (destructive operation)
             annotations: None,
         },
         Tool {
-            name: "delete_list".to_string(),
-            description: "Delete a list and all its cards".to_string(),
+            name: "create_list".to_string(),
+            description: "Create a new list (column) on a board".to_string(),
             input_schema: json!({
                 "type": "object",
                 "properties": {
-                    "list_id": {
+                    "board_id": {
                         "type": "string",
-                        "description": "The list ID to delete"
+                        "description": "The board ID to create the list on"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "The list name"
                     }
                 },
-                "required": ["list_id"]
+                "required": ["board_id", "name"]
             }),
-            // Not enabled for programmatic calling (destructive operation)
-            annotations: None,
+            annotations: programmatic_annotations(),
         },
-    ]
-}
-
-/// Dispatch a tool call to the appropriate handler
-pub async fn call_tool(client: &PlankaClient, name: &str, args: Option<Value>) -> ToolCallResult {
-    debug!(tool = %name, "Dispatching tool call");
-    trace!(tool = %name, args = ?args, "Tool call arguments");
-    
-    let result = match name {
-        "list_projects" => list_projects(client).await,
-        "list_boards" => list_boards(client, args).await,
-        "list_lists" => list_lists(client, args).await,
-        "list_cards" => list_cards(client, args).await,
-        "create_board" => create_board(client, args).await,
+        Tool {
+            name: "update_list".to_string(),
+            description: "Rename a list and/or move it to another position on its board".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "list_id": {
+                        "type": "string",
+                        "description": "The list ID to update"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "New list name (optional)"
+                    },
+                    "position": {
+                        "oneOf": [
+                            {"type": "string", "enum": ["top", "bottom"]},
+                            {"type": "number"}
+                        ],
+                        "description": "\"top\" (leftmost), \"bottom\" (rightmost) or a raw position value (optional)"
+                    },
+                    "before_list_id": {
+                        "type": "string",
+                        "description": "Place the list directly before this list (optional)"
+                    },
+                    "after_list_id": {
+                        "type": "string",
+                        "description": "Place the list directly after this list (optional)"
+                    }
+                },
+                "required": ["list_id"]
+            }),
+            annotations: programmatic_annotations(),
+        },
+        Tool {
+            name: "reorder_lists".to_string(),
+            description: "Set the order of all lists on a board. Lists are moved one at a time; if one fails, those already moved are put back".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "board_id": {
+                        "type": "string",
+                        "description": "The board ID"
+                    },
+                    "list_ids": {
+                        "type": "array",
+                        "items": {"type": "string"},
+                        "description": "Every list ID of the board, in the desired order"
+                    }
+                },
+                "required": ["board_id", "list_ids"]
+            }),
+            annotations: programmatic_annotations(),
+        },
+        Tool {
+            name: "apply_board_spec".to_string(),
+            description: "Reconcile a board toward a YAML or JSON spec (name, ordered lists, labels, seed cards): creates missing items, renames items matched by key and optionally prunes extra lists and labels. Returns the list of changes; use dry_run to preview them".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "spec": {
+                        "oneOf": [{"type": "string"}, {"type": "object"}],
+                        "description": "Board spec as a YAML/JSON string or an object: {name, board_id | project_id, lists: [{key?, name}], labels: [{key?, name, color}], cards: [{key?, name, list, description?, labels?}]}. Keys are Planka ids or current names"
+                    },
+                    "dry_run": {
+                        "type": "boolean",
+                        "description": "Only report the changes without applying them (default: false)"
+                    },
+                    "prune": {
+                        "type": "boolean",
+                        "description": "Delete lists (with their cards) and labels missing from the spec (default: false)"
+                    }
+                },
+                "required": ["spec"]
+            }),
+            // Not enabled for programmatic calling (may delete lists and labels with prune)
+            annotations: None,
+        },
+        Tool {
+            name: "create_card".to_string(),
+            description: "Create a new card in a list".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "list_id": {
+                        "type": "string",
+                        "description": "The list ID to create the card in"
+                    },
+                    "type": {
+                        "type": "string",
+                        "enum": ["project", "story"],
+                        "description": "Type of the card (project or story)",
+                        "default": "project"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "The card title"
+                    },
+                    "description": {
+                        "type": "string",
+                        "description": "Optional card description"
+                    },
+                    "due_date": {
+                        "type": "string",
+                        "format": "date-time",
+                        "description": "Optional due date (ISO 8601 format)"
+                    },
+                    "is_due_completed": {
+                        "type": "boolean",
+                        "description": "Whether the due date is completed"
+                    },
+                    "position": {
+                        "oneOf": [
+                            {"type": "string", "enum": ["top", "bottom"]},
+                            {"type": "number"}
+                        ],
+                        "description": "\"top\", \"bottom\" (default) or a raw position value"
+                    },
+                    "before_card_id": {
+                        "type": "string",
+                        "description": "Place the card directly before this card in the list"
+                    },
+                    "after_card_id": {
+                        "type": "string",
+                        "description": "Place the card directly after this card in the list"
+                    }
+                },
+                "required": ["list_id", "name"]
+            }),
+            annotations: programmatic_annotations(),
+        },
+        Tool {
+            name: "duplicate_card".to_string(),
+            description: "Copy a card with its description, labels, task lists and members into a list, optionally on another board (labels are matched by name there)".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "card_id": {
+                        "type": "string",
+                        "description": "The card ID to copy"
+                    },
+                    "list_id": {
+                        "type": "string",
+                        "description": "The list ID receiving the copy"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "Name of the copy (default: the source card's name)"
+                    },
+                    "parts": {
+                        "type": "array",
+                        "items": {
+                            "type": "string",
+                            "enum": ["description", "due_date", "labels", "task_lists", "members"]
+                        },
+                        "description": "Parts to copy (default: all)"
+                    },
+                    "position": {
+                        "oneOf": [
+                            {"type": "string", "enum": ["top", "bottom"]},
+                            {"type": "number"}
+                        ],
+                        "description": "\"top\", \"bottom\" (default) or a raw position value"
+                    },
+                    "before_card_id": {
+                        "type": "string",
+                        "description": "Place the copy directly before this card in the list"
+                    },
+                    "after_card_id": {
+                        "type": "string",
+                        "description": "Place the copy directly after this card in the list"
+                    }
+                },
+                "required": ["card_id", "list_id"]
+            }),
+            annotations: programmatic_annotations(),
+        },
+        Tool {
+            name: "import_cards".to_string(),
+            description: "Import cards into a board from CSV, Markdown bullet lists or a Trello JSON export, creating missing lists and labels. Re-running an import skips cards imported before; use dry_run to preview".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "board_id": {
+                        "type": "string",
+                        "description": "The board ID to import into"
+                    },
+                    "format": {
+                        "type": "string",
+                        "enum": ["csv", "markdown", "trello"],
+                        "description": "Format of content: csv (header row required), markdown (# headings as lists, top-level bullets as cards, indented lines as description) or trello (board JSON export)"
+                    },
+                    "content": {
+                        "type": "string",
+                        "description": "The document to import"
+                    },
+                    "columns": {
+                        "type": "object",
+                        "properties": {
+                            "id": {"type": "string"},
+                            "name": {"type": "string"},
+                            "description": {"type": "string"},
+                            "list": {"type": "string"},
+                            "labels": {"type": "string"},
+                            "due_date": {"type": "string"}
+                        },
+                        "description": "CSV header names for each card field (default: id, name or title, description, list, labels, due_date or due). Labels are separated by commas or semicolons; the id column keys re-runs"
+                    },
+                    "default_list": {
+                        "type": "string",
+                        "description": "List for cards without one (default: Imported)"
+                    },
+                    "dry_run": {
+                        "type": "boolean",
+                        "description": "Only report what would be created (default: false)"
+                    }
+                },
+                "required": ["board_id", "format", "content"]
+            }),
+            annotations: programmatic_annotations(),
+        },
+        Tool {
+            name: "update_card".to_string(),
+            description: "Update a card's properties (name, description, type, due date, etc.)".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "card_id": {
+                        "type": "string",
+                        "description": "The card ID to update"
+                    },
+                    "name": {
+                        "type": "string",
+                        "description": "New card title (optional)"
+                    },
+                    "description": {
+                        "type": "string",
+                        "description": "New card description (optional)"
+                    },
+                    "type": {
+                        "type": "string",
+                        "enum": ["project", "story"],
+                        "description": "Card type (optional)"
+                    },
+                    "due_date": {
+                        "type": "string",
+                        "format": "date-time",
+                        "description": "Due date in ISO 8601 format (optional)"
+                    },
+                    "is_due_completed": {
+                        "type": "boolean",
+                        "description": "Whether the due date is completed (optional)"
+                    },
+                    "board_id": {
+                        "type": "string",
+                        "description": "Move card to different board (optional)"
+                    },
+                    "cover_attachment_id": {
+                        "type": "string",
+                        "description": "Set cover image attachment ID (optional)"
+                    }
+                },
+                "required": ["card_id"]
+            }),
+            annotations: programmatic_annotations(),
+        },
+        with_selection_args(Tool {
+            name: "bulk_update_cards".to_string(),
+            description: "Apply the same change (description, type, due date) to many cards in one call, selected by id or by a filter. Returns a per-card success/failure report".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "description": {
+                        "type": "string",
+                        "description": "New card description (optional)"
+                    },
+                    "type": {
+                        "type": "string",
+                        "enum": ["project", "story"],
+                        "description": "Card type (optional)"
+                    },
+                    "due_date": {
+                        "type": "string",
+                        "format": "date-time",
+                        "description": "Due date in ISO 8601 format (optional)"
+                    },
+                    "is_due_completed": {
+                        "type": "boolean",
+                        "description": "Whether the due date is completed (optional)"
+                    }
+                },
+                "required": []
+            }),
+            annotations: programmatic_annotations(),
+        }),
+        Tool {
+            name: "move_card".to_string(),
+            description: "Move a card to a different list or position".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "card_id": {
+                        "type": "string",
+                        "description": "The card ID to move"
+                    },
+                    "list_id": {
+                        "type": "string",
+                        "description": "The target list ID"
+                    },
+                    "position": {
+                        "oneOf": [
+                            {"type": "string", "enum": ["top", "bottom"]},
+                            {"type": "number"}
+                        ],
+                        "description": "\"top\", \"bottom\" (default) or a raw position value"
+                    },
+                    "before_card_id": {
+                        "type": "string",
+                        "description": "Place the card directly before this card in the list"
+                    },
+                    "after_card_id": {
+                        "type": "string",
+                        "description": "Place the card directly after this card in the list"
+                    }
+                },
+                "required": ["card_id", "list_id"]
+            }),
+            annotations: programmatic_annotations(),
+        },
+        with_selection_args(Tool {
+            name: "bulk_move_cards".to_string(),
+            description: "Move many cards to a list in one call, selected by id or by a filter, keeping their order. Returns a per-card success/failure report".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "list_id": {
+                        "type": "string",
+                        "description": "The target list ID"
+                    },
+                    "position": {
+                        "type": "string",
+                        "enum": ["top", "bottom"],
+                        "description": "Add the cards at the top or the bottom (default) of the list"
+                    }
+                },
+                "required": ["list_id"]
+            }),
+            annotations: programmatic_annotations(),
+        }),
+        Tool {
+            name: "delete_card".to_string(),
+            description: "Delete a card".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "card_id": {
+                        "type": "string",
+                        "description": "The card ID to delete"
+                    }
+                },
+                "required": ["card_id"]
+            }),
+            // Not enabled for programmatic calling (destructive operation)
+            annotations: None,
+        },
+        with_selection_args(Tool {
+            name: "bulk_delete_cards".to_string(),
+            description: "Delete many cards in one call, selected by id or by a filter. A filter with only board_id needs expected_count. Returns a per-card success/failure report".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "expected_count": {
+                        "type": "integer",
+                        "minimum": 0,
+                        "description": "Number of cards the selection must match, or nothing is deleted. Required when the filter has nothing but board_id"
+                    }
+                },
+                "required": []
+            }),
+            // Not enabled for programmatic calling (destructive operation)
+            annotations: None,
+        }),
+        Tool {
+            name: "delete_list".to_string(),
+            description: "Delete a list and all its cards".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "list_id": {
+                        "type": "string",
+                        "description": "The list ID to delete"
+                    }
+                },
+                "required": ["list_id"]
+            }),
+            // Not enabled for programmatic calling (destructive operation)
+            annotations: None,
+        },
+        Tool {
+            name: "batch".to_string(),
+            description: "Run an ordered list of create, update and move operations as one unit. Later steps can reference results of earlier ones as \"$<step>.<field>\" (e.g. \"$todo.id\" or \"$0.id\"). If a step fails, everything done so far is undone: created items are deleted and changed items get their previous state back".to_string(),
+            input_schema: json!({
+                "type": "object",
+                "properties": {
+                    "operations": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "id": {
+                                    "type": "string",
+                                    "description": "Name for referencing this step's result (optional; steps can also be referenced by index)"
+                                },
+                                "tool": {
+                                    "type": "string",
+                                    "enum": batch::BATCH_TOOLS,
+                                    "description": "Tool to run"
+                                },
+                                "args": {
+                                    "type": "object",
+                                    "description": "Tool arguments; string values \"$<step>.<path>\" are replaced with that part of an earlier result (\"$$\" for a literal \"$\")"
+                                }
+                            },
+                            "required": ["tool", "args"]
+                        },
+                        "description": "Operations to run in order"
+                    }
+                },
+                "required": ["operations"]
+            }),
+            // Not enabled for programmatic calling (rollback deletes what the batch created)
+            annotations: None,
+        },
+    ]
+}
+
+/// Dispatch a tool call to the appropriate handler
+pub async fn call_tool(registry: &ClientRegistry, name: &str, args: Option<Value>) -> ToolCallResult {
+    debug!(tool = %name, "Dispatching tool call");
+    trace!(tool = %name, args = ?args, "Tool call arguments");
+
+    let result = if name == "list_instances" {
+        list_instances(registry)
+    } else {
+        let instance = args
+            .as_ref()
+            .and_then(|a| a.get("instance"))
+            .and_then(Value::as_str);
+        match registry.get(instance) {
+            Ok(client) => dispatch(client, name, args).await,
+            Err(e) => {
+                error!(tool = %name, error = %e, "Failed to resolve Planka instance");
+                ToolCallResult::error(e.to_string())
+            }
+        }
+    };
+    
+    match &result {
+        ToolCallResult { is_error: Some(true), .. } => {
+            warn!(tool = %name, "Tool call failed");
+            trace!(tool = %name, result = ?result, "Tool failure details");
+        }
+        _ => {
+            info!(tool = %name, "Tool call succeeded");
+            trace!(tool = %name, result = ?result, "Tool success details");
+        }
+    }
+    
+    result
+}
+
+async fn dispatch(client: &PlankaClient, name: &str, args: Option<Value>) -> ToolCallResult {
+    match name {
+        "list_projects" => list_projects(client, args).await,
+        "list_boards" => list_boards(client, args).await,
+        "list_lists" => list_lists(client, args).await,
+        "list_cards" => list_cards(client, args).await,
+        "get_board" => get_board(client, args).await,
+        "export_board" => export_board(client, args).await,
+        "create_project" => create_project(client, args).await,
+        "update_project" => update_project(client, args).await,
+        "delete_project" => delete_project(client, args).await,
+        "backup_project" => backup_project(client, args).await,
+        "restore_project" => restore_project(client, args).await,
+        "add_project_manager" => add_project_manager(client, args).await,
+        "remove_project_manager" => remove_project_manager(client, args).await,
+        "create_board" => create_board(client, args).await,
+        "clone_board" => clone_board(client, args).await,
+        "update_board" => update_board(client, args).await,
+        "delete_board" => delete_board(client, args).await,
+        "list_board_members" => list_board_members(client, args).await,
+        "add_board_member" => add_board_member(client, args).await,
+        "update_board_member" => update_board_member(client, args).await,
+        "remove_board_member" => remove_board_member(client, args).await,
         "create_list" => create_list(client, args).await,
+        "update_list" => update_list(client, args).await,
+        "reorder_lists" => reorder_lists(client, args).await,
+        "apply_board_spec" => apply_board_spec(client, args).await,
         "create_card" => create_card(client, args).await,
+        "duplicate_card" => duplicate_card(client, args).await,
+        "import_cards" => import_cards(client, args).await,
         "update_card" => update_card(client, args).await,
+        "bulk_update_cards" => bulk_update_cards(client, args).await,
         "move_card" => move_card(client, args).await,
+        "bulk_move_cards" => bulk_move_cards(client, args).await,
         "delete_card" => delete_card(client, args).await,
+        "bulk_delete_cards" => bulk_delete_cards(client, args).await,
         "delete_list" => delete_list(client, args).await,
+        "batch" => batch(client, args).await,
         _ => {
             error!(tool = %name, "Unknown tool requested");
             ToolCallResult::error(format!("Unknown tool: {name}"))
         }
+    }
+}
+
+fn list_instances(registry: &ClientRegistry) -> ToolCallResult {
+    debug!("Executing list_instances tool");
+    let instances: Vec<Value> = registry
+        .iter()
+        .map(|(name, client)| {
+            json!({
+                "name": name,
+                "url": client.base_url().as_str(),
+                "default": name == registry.default_name()
+            })
+        })
+        .collect();
+    let json = serde_json::to_string_pretty(&instances).unwrap_or_default();
+    ToolCallResult::text(json)
+}
+
+
+async fn list_projects(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    debug!("Executing list_projects tool");
+    let args: ListingArgs = match args.map(serde_json::from_value).transpose() {
+        Ok(a) => a.unwrap_or_default(),
+        Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+    };
+
+    match client.list_projects().await {
+        Ok(projects) => {
+            info!(count = projects.len(), "Projects listed successfully");
+            listing_result(projects, &args, PROJECT_FIELDS)
+        }
+        Err(e) => {
+            error!(error = %e, "Failed to list projects");
+            ToolCallResult::error(format!("Failed to list projects: {e}"))
+        }
+    }
+}
+
+/// Output options shared by the listing tools
+#[derive(Deserialize, Default)]
+struct ListingArgs {
+    #[serde(default)]
+    format: OutputFormat,
+    fields: Option<Vec<String>>,
+    limit: Option<usize>,
+    cursor: Option<String>,
+}
+
+/// Renders one page of the output of a listing tool
+fn listing_result<T: serde::Serialize + PageOrder>(items: Vec<T>, args: &ListingArgs, default_fields: &[&str]) -> ToolCallResult {
+    let total = items.len();
+    let (page, next_cursor) = match paginate(items, args.limit, args.cursor.as_deref()) {
+        Ok(page) => page,
+        Err(e) => return ToolCallResult::error(e),
+    };
+    debug!(total, returned = page.len(), has_more = next_cursor.is_some(), "Paginated listing");
+    match render(&page, args.format, args.fields.as_deref(), default_fields, next_cursor.as_deref()) {
+        Ok(text) => ToolCallResult::text(text),
+        Err(e) => ToolCallResult::error(e),
+    }
+}
+
+#[derive(Deserialize)]
+struct ListBoardsArgs {
+    project_id: String,
+    #[serde(default)]
+    refresh: bool,
+    #[serde(flatten)]
+    listing: ListingArgs,
+}
+
+async fn list_boards(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: ListBoardsArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required argument: project_id"),
+    };
+
+    match client.list_boards(&args.project_id, args.refresh).await {
+        Ok(boards) => listing_result(boards, &args.listing, BOARD_FIELDS),
+        Err(e) => ToolCallResult::error(format!("Failed to list boards: {e}")),
+    }
+}
+
+#[derive(Deserialize)]
+struct ListListsArgs {
+    board_id: String,
+    #[serde(default)]
+    refresh: bool,
+    #[serde(flatten)]
+    listing: ListingArgs,
+}
+
+async fn list_lists(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: ListListsArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required argument: board_id"),
+    };
+
+    match client.list_lists(&args.board_id, args.refresh).await {
+        Ok(lists) => listing_result(lists, &args.listing, LIST_FIELDS),
+        Err(e) => ToolCallResult::error(format!("Failed to list lists: {e}")),
+    }
+}
+
+#[derive(Deserialize)]
+struct ListCardsArgs {
+    board_id: String,
+    #[serde(default)]
+    refresh: bool,
+    #[serde(flatten)]
+    listing: ListingArgs,
+}
+
+async fn list_cards(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: ListCardsArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required argument: board_id"),
+    };
+
+    match client.list_cards(&args.board_id, args.refresh).await {
+        Ok(cards) => listing_result(cards, &args.listing, CARD_FIELDS),
+        Err(e) => ToolCallResult::error(format!("Failed to list cards: {e}")),
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+enum OverviewFormat {
+    #[default]
+    Markdown,
+    Json,
+}
+
+#[derive(Deserialize)]
+struct GetBoardArgs {
+    board_id: String,
+    #[serde(default)]
+    format: OverviewFormat,
+    #[serde(default)]
+    refresh: bool,
+}
+
+async fn get_board(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: GetBoardArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required argument: board_id"),
+    };
+
+    match client.get_board(&args.board_id, args.refresh).await {
+        Ok(data) => {
+            let overview = BoardOverview::new(&data);
+            match args.format {
+                OverviewFormat::Markdown => ToolCallResult::text(overview.to_markdown()),
+                OverviewFormat::Json => {
+                    let json = serde_json::to_string_pretty(&overview).unwrap_or_default();
+                    ToolCallResult::text(json)
+                }
+            }
+        }
+        Err(e) => ToolCallResult::error(format!("Failed to get board: {e}")),
+    }
+}
+
+#[derive(Deserialize)]
+struct ExportBoardArgs {
+    board_id: String,
+    #[serde(default)]
+    format: ExportFormat,
+}
+
+async fn export_board(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    use crate::planka::types::BoardResponse;
+
+    let args: ExportBoardArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required argument: board_id"),
+    };
+
+    let raw = match client.export_board(&args.board_id).await {
+        Ok(raw) => raw,
+        Err(e) => return ToolCallResult::error(format!("Failed to export board: {e}")),
+    };
+    let document = match args.format {
+        ExportFormat::Json => Ok(serde_json::to_string_pretty(&raw).unwrap_or_default()),
+        ExportFormat::Markdown | ExportFormat::Csv => match serde_json::from_value::<BoardResponse>(raw) {
+            Ok(data) if args.format == ExportFormat::Markdown => Ok(export::to_markdown(&data)),
+            Ok(data) => export::to_csv(&data),
+            Err(e) => Err(format!("Failed to parse board: {e}")),
+        },
+    };
+
+    match document {
+        Ok(text) => {
+            let uri = format!("planka://boards/{}/export.{}", args.board_id, args.format.extension());
+            ToolCallResult::resource(uri, args.format.mime_type(), text)
+        }
+        Err(e) => ToolCallResult::error(format!("Failed to export board: {e}")),
+    }
+}
+
+#[derive(Deserialize)]
+struct CreateProjectArgs {
+    name: String,
+    #[serde(rename = "type", default = "default_project_type")]
+    project_type: String,
+    description: Option<String>,
+}
+
+fn default_project_type() -> String {
+    "private".to_string()
+}
+
+async fn create_project(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: CreateProjectArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required argument: name"),
     };
-    
-    match &result {
-        ToolCallResult { is_error: Some(true), .. } => {
-            warn!(tool = %name, "Tool call failed");
-            trace!(tool = %name, result = ?result, "Tool failure details");
+
+    let project_type = args.project_type.to_lowercase();
+    if project_type != "private" && project_type != "shared" {
+        return ToolCallResult::error("Invalid project type. Must be 'private' or 'shared'");
+    }
+
+    match client.create_project(&args.name, &project_type, args.description).await {
+        Ok(project) => {
+            let json = serde_json::to_string_pretty(&project).unwrap_or_default();
+            ToolCallResult::text(json)
         }
-        _ => {
-            info!(tool = %name, "Tool call succeeded");
-            trace!(tool = %name, result = ?result, "Tool success details");
+        Err(e) => ToolCallResult::error(format!("Failed to create project: {e}")),
+    }
+}
+
+#[derive(Deserialize)]
+struct UpdateProjectArgs {
+    project_id: String,
+    name: Option<String>,
+    description: Option<String>,
+    background_gradient: Option<String>,
+}
+
+async fn update_project(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: UpdateProjectArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required argument: project_id"),
+    };
+
+    use crate::planka::types::UpdateProjectOptions;
+    let options = UpdateProjectOptions {
+        name: args.name,
+        description: args.description,
+        background_gradient: args.background_gradient,
+    };
+
+    match client.update_project(&args.project_id, options).await {
+        Ok(project) => {
+            let json = serde_json::to_string_pretty(&project).unwrap_or_default();
+            ToolCallResult::text(json)
         }
+        Err(e) => ToolCallResult::error(format!("Failed to update project: {e}")),
     }
-    
-    result
 }
 
-async fn list_projects(client: &PlankaClient) -> ToolCallResult {
-    debug!("Executing list_projects tool");
-    match client.list_projects().await {
-        Ok(projects) => {
-            info!(count = projects.len(), "Projects listed successfully");
-            let json = serde_json::to_string_pretty(&projects).unwrap_or_default();
+#[derive(Deserialize)]
+struct DeleteProjectArgs {
+    project_id: String,
+    confirm: String,
+}
+
+async fn delete_project(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: DeleteProjectArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required arguments: project_id, confirm"),
+    };
+
+    match client.delete_project(&args.project_id, &args.confirm).await {
+        Ok(()) => ToolCallResult::text("Project deleted successfully"),
+        Err(e) => ToolCallResult::error(format!("Failed to delete project: {e}")),
+    }
+}
+
+#[derive(Deserialize)]
+struct BackupProjectArgs {
+    project_id: String,
+}
+
+async fn backup_project(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: BackupProjectArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required argument: project_id"),
+    };
+
+    match client.backup_project(&args.project_id).await {
+        Ok(backup) => {
+            let uri = format!("planka://projects/{}/backup.json", args.project_id);
+            let json = serde_json::to_string_pretty(&backup).unwrap_or_default();
+            ToolCallResult::resource(uri, "application/json", json)
+        }
+        Err(e) => ToolCallResult::error(format!("Failed to back up project: {e}")),
+    }
+}
+
+#[derive(Deserialize)]
+struct RestoreProjectArgs {
+    backup: Value,
+    name: Option<String>,
+}
+
+async fn restore_project(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: RestoreProjectArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required argument: backup"),
+    };
+
+    let backup = match args.backup {
+        Value::String(s) => serde_json::from_str::<ProjectBackup>(&s),
+        other => serde_json::from_value::<ProjectBackup>(other),
+    };
+    let backup = match backup {
+        Ok(b) => b,
+        Err(e) => return ToolCallResult::error(format!("Invalid backup: {e}")),
+    };
+
+    match client.restore_project(&backup, args.name.as_deref()).await {
+        Ok(report) => {
+            let json = serde_json::to_string_pretty(&report).unwrap_or_default();
+            ToolCallResult::text(json)
+        }
+        Err(e) => ToolCallResult::error(format!("Failed to restore project: {e}")),
+    }
+}
+
+#[derive(Deserialize)]
+struct ProjectManagerArgs {
+    project_id: String,
+    user_id: String,
+}
+
+async fn add_project_manager(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: ProjectManagerArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required arguments: project_id, user_id"),
+    };
+
+    match client.add_project_manager(&args.project_id, &args.user_id).await {
+        Ok(manager) => {
+            let json = serde_json::to_string_pretty(&manager).unwrap_or_default();
+            ToolCallResult::text(json)
+        }
+        Err(e) => ToolCallResult::error(format!("Failed to add project manager: {e}")),
+    }
+}
+
+async fn remove_project_manager(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: ProjectManagerArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required arguments: project_id, user_id"),
+    };
+
+    match client.remove_project_manager(&args.project_id, &args.user_id).await {
+        Ok(()) => ToolCallResult::text("Project manager removed successfully"),
+        Err(e) => ToolCallResult::error(format!("Failed to remove project manager: {e}")),
+    }
+}
+
+#[derive(Deserialize)]
+struct CreateBoardArgs {
+    project_id: String,
+    name: String,
+}
+
+async fn create_board(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: CreateBoardArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required arguments: project_id, name"),
+    };
+
+    match client.create_board(&args.project_id, &args.name).await {
+        Ok(board) => {
+            let json = serde_json::to_string_pretty(&board).unwrap_or_default();
+            ToolCallResult::text(json)
+        }
+        Err(e) => ToolCallResult::error(format!("Failed to create board: {e}")),
+    }
+}
+
+#[derive(Deserialize)]
+struct CloneBoardArgs {
+    board_id: String,
+    project_id: String,
+    name: String,
+    include_labels: Option<bool>,
+    include_cards: Option<bool>,
+}
+
+async fn clone_board(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: CloneBoardArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required arguments: board_id, project_id, name"),
+    };
+
+    let options = CloneBoardOptions {
+        project_id: args.project_id,
+        name: args.name,
+        labels: args.include_labels.unwrap_or(true),
+        cards: args.include_cards.unwrap_or(false),
+    };
+
+    match client.clone_board(&args.board_id, options).await {
+        Ok(report) => {
+            let json = serde_json::to_string_pretty(&report).unwrap_or_default();
+            ToolCallResult::text(json)
+        }
+        Err(e) => ToolCallResult::error(format!("Failed to clone board: {e}")),
+    }
+}
+
+#[derive(Deserialize)]
+struct UpdateBoardArgs {
+    board_id: String,
+    name: Option<String>,
+    position: Option<PositionArg>,
+    before_board_id: Option<String>,
+    after_board_id: Option<String>,
+}
+
+async fn update_board(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: UpdateBoardArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required argument: board_id"),
+    };
+
+    let placement = match placement(args.position, args.before_board_id, args.after_board_id, "board") {
+        Ok(p) => p,
+        Err(e) => return ToolCallResult::error(e),
+    };
+
+    use crate::planka::types::UpdateBoardOptions;
+    let options = UpdateBoardOptions {
+        name: args.name,
+        placement,
+    };
+
+    match client.update_board(&args.board_id, options).await {
+        Ok(board) => {
+            let json = serde_json::to_string_pretty(&board).unwrap_or_default();
             ToolCallResult::text(json)
         }
-        Err(e) => {
-            error!(error = %e, "Failed to list projects");
-            ToolCallResult::error(format!("Failed to list projects: {e}"))
-        }
+        Err(e) => ToolCallResult::error(format!("Failed to update board: {e}")),
     }
 }
 
 #[derive(Deserialize)]
-struct ListBoardsArgs {
-    project_id: String,
+struct BoardArgs {
+    board_id: String,
 }
 
-async fn list_boards(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
-    let args: ListBoardsArgs = match args {
+#[derive(Deserialize)]
+struct DeleteBoardArgs {
+    board_id: String,
+    confirm: String,
+}
+
+async fn delete_board(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: DeleteBoardArgs = match args {
         Some(v) => match serde_json::from_value(v) {
             Ok(a) => a,
             Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
         },
-        None => return ToolCallResult::error("Missing required argument: project_id"),
+        None => return ToolCallResult::error("Missing required arguments: board_id, confirm"),
     };
 
-    match client.list_boards(&args.project_id).await {
-        Ok(boards) => {
-            let json = serde_json::to_string_pretty(&boards).unwrap_or_default();
-            ToolCallResult::text(json)
-        }
-        Err(e) => ToolCallResult::error(format!("Failed to list boards: {e}")),
+    match client.delete_board(&args.board_id, &args.confirm).await {
+        Ok(()) => ToolCallResult::text("Board deleted successfully"),
+        Err(e) => ToolCallResult::error(format!("Failed to delete board: {e}")),
     }
 }
 
-#[derive(Deserialize)]
-struct ListListsArgs {
-    board_id: String,
-}
-
-async fn list_lists(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
-    let args: ListListsArgs = match args {
+async fn list_board_members(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: BoardArgs = match args {
         Some(v) => match serde_json::from_value(v) {
             Ok(a) => a,
             Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
@@ -338,59 +1597,92 @@ async fn list_lists(client: &PlankaClient, args: Option<Value>) -> ToolCallResul
         None => return ToolCallResult::error("Missing required argument: board_id"),
     };
 
-    match client.list_lists(&args.board_id).await {
-        Ok(lists) => {
-            let json = serde_json::to_string_pretty(&lists).unwrap_or_default();
+    match client.list_board_members(&args.board_id).await {
+        Ok(members) => {
+            let json = serde_json::to_string_pretty(&members).unwrap_or_default();
             ToolCallResult::text(json)
         }
-        Err(e) => ToolCallResult::error(format!("Failed to list lists: {e}")),
+        Err(e) => ToolCallResult::error(format!("Failed to list board members: {e}")),
     }
 }
 
 #[derive(Deserialize)]
-struct ListCardsArgs {
+struct BoardMemberArgs {
     board_id: String,
+    user_id: String,
+    role: Option<String>,
+    can_comment: Option<bool>,
 }
 
-async fn list_cards(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
-    let args: ListCardsArgs = match args {
+/// Validates a board membership role
+fn board_role(role: &str) -> Result<String, ToolCallResult> {
+    match role.to_lowercase().as_str() {
+        r @ ("editor" | "viewer") => Ok(r.to_string()),
+        _ => Err(ToolCallResult::error("Invalid role. Must be 'editor' or 'viewer'")),
+    }
+}
+
+async fn add_board_member(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: BoardMemberArgs = match args {
         Some(v) => match serde_json::from_value(v) {
             Ok(a) => a,
             Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
         },
-        None => return ToolCallResult::error("Missing required argument: board_id"),
+        None => return ToolCallResult::error("Missing required arguments: board_id, user_id"),
+    };
+
+    let role = match board_role(args.role.as_deref().unwrap_or("editor")) {
+        Ok(r) => r,
+        Err(e) => return e,
     };
 
-    match client.list_cards(&args.board_id).await {
-        Ok(cards) => {
-            let json = serde_json::to_string_pretty(&cards).unwrap_or_default();
+    match client.add_board_member(&args.board_id, &args.user_id, &role, args.can_comment).await {
+        Ok(membership) => {
+            let json = serde_json::to_string_pretty(&membership).unwrap_or_default();
             ToolCallResult::text(json)
         }
-        Err(e) => ToolCallResult::error(format!("Failed to list cards: {e}")),
+        Err(e) => ToolCallResult::error(format!("Failed to add board member: {e}")),
     }
 }
 
-#[derive(Deserialize)]
-struct CreateBoardArgs {
-    project_id: String,
-    name: String,
-}
-
-async fn create_board(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
-    let args: CreateBoardArgs = match args {
+async fn update_board_member(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: BoardMemberArgs = match args {
         Some(v) => match serde_json::from_value(v) {
             Ok(a) => a,
             Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
         },
-        None => return ToolCallResult::error("Missing required arguments: project_id, name"),
+        None => return ToolCallResult::error("Missing required arguments: board_id, user_id"),
     };
 
-    match client.create_board(&args.project_id, &args.name).await {
-        Ok(board) => {
-            let json = serde_json::to_string_pretty(&board).unwrap_or_default();
+    let role = match args.role.as_deref().map(board_role).transpose() {
+        Ok(r) => r,
+        Err(e) => return e,
+    };
+
+    match client
+        .update_board_member(&args.board_id, &args.user_id, role.as_deref(), args.can_comment)
+        .await
+    {
+        Ok(membership) => {
+            let json = serde_json::to_string_pretty(&membership).unwrap_or_default();
             ToolCallResult::text(json)
         }
-        Err(e) => ToolCallResult::error(format!("Failed to create board: {e}")),
+        Err(e) => ToolCallResult::error(format!("Failed to update board member: {e}")),
+    }
+}
+
+async fn remove_board_member(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: BoardMemberArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required arguments: board_id, user_id"),
+    };
+
+    match client.remove_board_member(&args.board_id, &args.user_id).await {
+        Ok(()) => ToolCallResult::text("Board member removed successfully"),
+        Err(e) => ToolCallResult::error(format!("Failed to remove board member: {e}")),
     }
 }
 
@@ -418,6 +1710,138 @@ async fn create_list(client: &PlankaClient, args: Option<Value>) -> ToolCallResu
     }
 }
 
+#[derive(Deserialize)]
+struct UpdateListArgs {
+    list_id: String,
+    name: Option<String>,
+    position: Option<PositionArg>,
+    before_list_id: Option<String>,
+    after_list_id: Option<String>,
+}
+
+async fn update_list(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: UpdateListArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required argument: list_id"),
+    };
+
+    let placement = match placement(args.position, args.before_list_id, args.after_list_id, "list") {
+        Ok(p) => p,
+        Err(e) => return ToolCallResult::error(e),
+    };
+
+    use crate::planka::types::UpdateListOptions;
+    let options = UpdateListOptions {
+        name: args.name,
+        placement,
+    };
+
+    match client.update_list(&args.list_id, options).await {
+        Ok(list) => {
+            let json = serde_json::to_string_pretty(&list).unwrap_or_default();
+            ToolCallResult::text(json)
+        }
+        Err(e) => ToolCallResult::error(format!("Failed to update list: {e}")),
+    }
+}
+
+#[derive(Deserialize)]
+struct ReorderListsArgs {
+    board_id: String,
+    list_ids: Vec<String>,
+}
+
+async fn reorder_lists(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: ReorderListsArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required arguments: board_id, list_ids"),
+    };
+
+    match client.reorder_lists(&args.board_id, &args.list_ids).await {
+        Ok(lists) => {
+            let json = serde_json::to_string_pretty(&lists).unwrap_or_default();
+            ToolCallResult::text(json)
+        }
+        Err(e) => ToolCallResult::error(format!("Failed to reorder lists: {e}")),
+    }
+}
+
+/// `position` argument: a raw value or a named end of the list
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PositionArg {
+    Exact(f64),
+    Named(NamedPosition),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum NamedPosition {
+    Top,
+    Bottom,
+}
+
+/// Combines the mutually exclusive positioning arguments; `None` when none
+/// of them is given
+fn placement(
+    position: Option<PositionArg>,
+    before: Option<String>,
+    after: Option<String>,
+    kind: &str,
+) -> Result<Option<Placement>, String> {
+    match (position, before, after) {
+        (None, None, None) => Ok(None),
+        (Some(PositionArg::Named(NamedPosition::Top)), None, None) => Ok(Some(Placement::Top)),
+        (Some(PositionArg::Named(NamedPosition::Bottom)), None, None) => Ok(Some(Placement::Bottom)),
+        (Some(PositionArg::Exact(p)), None, None) => Ok(Some(Placement::At(p))),
+        (None, Some(id), None) => Ok(Some(Placement::Before(id))),
+        (None, None, Some(id)) => Ok(Some(Placement::After(id))),
+        _ => Err(format!("Use only one of position, before_{kind}_id and after_{kind}_id")),
+    }
+}
+
+#[derive(Deserialize)]
+struct ApplyBoardSpecArgs {
+    spec: Value,
+    dry_run: Option<bool>,
+    prune: Option<bool>,
+}
+
+async fn apply_board_spec(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: ApplyBoardSpecArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required argument: spec"),
+    };
+
+    let text = match &args.spec {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    let spec = match BoardSpec::parse(&text) {
+        Ok(s) => s,
+        Err(e) => return ToolCallResult::error(e.to_string()),
+    };
+    let options = ApplyOptions {
+        prune: args.prune.unwrap_or(false),
+        dry_run: args.dry_run.unwrap_or(false),
+    };
+
+    match client.apply_board_spec(&spec, options).await {
+        Ok(report) if report.error.is_some() => ToolCallResult::error(report.to_text()),
+        Ok(report) => ToolCallResult::text(report.to_text()),
+        Err(e) => ToolCallResult::error(format!("Failed to apply board spec: {e}")),
+    }
+}
+
 #[derive(Deserialize)]
 struct CreateCardArgs {
     list_id: String,
@@ -427,6 +1851,9 @@ struct CreateCardArgs {
     description: Option<String>,
     due_date: Option<String>,
     is_due_completed: Option<bool>,
+    position: Option<PositionArg>,
+    before_card_id: Option<String>,
+    after_card_id: Option<String>,
 }
 
 fn default_card_type() -> String {
@@ -450,8 +1877,14 @@ async fn create_card(client: &PlankaClient, args: Option<Value>) -> ToolCallResu
         _ => return ToolCallResult::error("Invalid card type. Must be 'project' or 'story'"),
     };
 
+    let placement = match placement(args.position, args.before_card_id, args.after_card_id, "card") {
+        Ok(p) => p.unwrap_or_default(),
+        Err(e) => return ToolCallResult::error(e),
+    };
+
     let options = CreateCardOptions {
         list_id: args.list_id,
+        placement,
         card_type,
         name: args.name,
         description: args.description,
@@ -469,6 +1902,82 @@ async fn create_card(client: &PlankaClient, args: Option<Value>) -> ToolCallResu
     }
 }
 
+#[derive(Deserialize)]
+struct DuplicateCardArgs {
+    card_id: String,
+    list_id: String,
+    name: Option<String>,
+    parts: Option<Vec<CardPart>>,
+    position: Option<PositionArg>,
+    before_card_id: Option<String>,
+    after_card_id: Option<String>,
+}
+
+async fn duplicate_card(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: DuplicateCardArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required arguments: card_id, list_id"),
+    };
+
+    let placement = match placement(args.position, args.before_card_id, args.after_card_id, "card") {
+        Ok(p) => p.unwrap_or_default(),
+        Err(e) => return ToolCallResult::error(e),
+    };
+
+    let options = DuplicateCardOptions {
+        list_id: args.list_id,
+        name: args.name,
+        placement,
+        parts: args.parts.unwrap_or_else(|| CardPart::ALL.to_vec()),
+    };
+
+    match client.duplicate_card(&args.card_id, options).await {
+        Ok(report) => {
+            let json = serde_json::to_string_pretty(&report).unwrap_or_default();
+            ToolCallResult::text(json)
+        }
+        Err(e) => ToolCallResult::error(format!("Failed to duplicate card: {e}")),
+    }
+}
+
+#[derive(Deserialize)]
+struct ImportCardsArgs {
+    board_id: String,
+    format: ImportFormat,
+    content: String,
+    #[serde(default)]
+    columns: CsvColumns,
+    default_list: Option<String>,
+    dry_run: Option<bool>,
+}
+
+async fn import_cards(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: ImportCardsArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required arguments: board_id, format, content"),
+    };
+
+    let default_list = args.default_list.as_deref().unwrap_or("Imported");
+    let batch = match import::parse(args.format, &args.content, &args.columns, default_list) {
+        Ok(b) => b,
+        Err(e) => return ToolCallResult::error(e.to_string()),
+    };
+
+    match client.import_cards(&args.board_id, batch, args.dry_run.unwrap_or(false)).await {
+        Ok(report) => {
+            let json = serde_json::to_string_pretty(&report).unwrap_or_default();
+            ToolCallResult::text(json)
+        }
+        Err(e) => ToolCallResult::error(format!("Failed to import cards: {e}")),
+    }
+}
+
 #[derive(Deserialize)]
 struct UpdateCardArgs {
     card_id: String,
@@ -526,7 +2035,9 @@ async fn update_card(client: &PlankaClient, args: Option<Value>) -> ToolCallResu
 struct MoveCardArgs {
     card_id: String,
     list_id: String,
-    position: Option<f64>,
+    position: Option<PositionArg>,
+    before_card_id: Option<String>,
+    after_card_id: Option<String>,
 }
 
 async fn move_card(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
@@ -538,7 +2049,12 @@ async fn move_card(client: &PlankaClient, args: Option<Value>) -> ToolCallResult
         None => return ToolCallResult::error("Missing required arguments: card_id, list_id"),
     };
 
-    match client.move_card(&args.card_id, &args.list_id, args.position).await {
+    let placement = match placement(args.position, args.before_card_id, args.after_card_id, "card") {
+        Ok(p) => p.unwrap_or_default(),
+        Err(e) => return ToolCallResult::error(e),
+    };
+
+    match client.move_card(&args.card_id, &args.list_id, placement).await {
         Ok(card) => {
             let json = serde_json::to_string_pretty(&card).unwrap_or_default();
             ToolCallResult::text(json)
@@ -567,6 +2083,127 @@ async fn delete_card(client: &PlankaClient, args: Option<Value>) -> ToolCallResu
     }
 }
 
+#[derive(Deserialize)]
+struct BulkUpdateCardsArgs {
+    #[serde(flatten)]
+    selection: SelectionArgs,
+    description: Option<String>,
+    #[serde(rename = "type")]
+    card_type: Option<String>,
+    due_date: Option<String>,
+    is_due_completed: Option<bool>,
+}
+
+async fn bulk_update_cards(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: BulkUpdateCardsArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required argument: card_ids or filter"),
+    };
+    let selection = match args.selection.selection() {
+        Ok(s) => s,
+        Err(e) => return ToolCallResult::error(e),
+    };
+
+    use crate::planka::types::{CardType, UpdateCardOptions};
+    let card_type = match args.card_type.as_deref().map(str::to_lowercase).as_deref() {
+        Some("project") => Some(CardType::Project),
+        Some("story") => Some(CardType::Story),
+        Some(_) => return ToolCallResult::error("Invalid card type. Must be 'project' or 'story'"),
+        None => None,
+    };
+    let options = UpdateCardOptions {
+        description: args.description,
+        card_type,
+        due_date: args.due_date,
+        is_due_completed: args.is_due_completed,
+        ..Default::default()
+    };
+    if options.description.is_none()
+        && options.card_type.is_none()
+        && options.due_date.is_none()
+        && options.is_due_completed.is_none()
+    {
+        return ToolCallResult::error("Nothing to update: give description, type, due_date or is_due_completed");
+    }
+
+    match client.bulk_update_cards(&selection, options, args.selection.concurrency()).await {
+        Ok(report) => {
+            let json = serde_json::to_string_pretty(&report).unwrap_or_default();
+            ToolCallResult::text(json)
+        }
+        Err(e) => ToolCallResult::error(format!("Failed to update cards: {e}")),
+    }
+}
+
+#[derive(Deserialize)]
+struct BulkMoveCardsArgs {
+    #[serde(flatten)]
+    selection: SelectionArgs,
+    list_id: String,
+    position: Option<NamedPosition>,
+}
+
+async fn bulk_move_cards(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: BulkMoveCardsArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required argument: list_id"),
+    };
+    let selection = match args.selection.selection() {
+        Ok(s) => s,
+        Err(e) => return ToolCallResult::error(e),
+    };
+    let placement = match args.position {
+        Some(NamedPosition::Top) => Placement::Top,
+        Some(NamedPosition::Bottom) | None => Placement::Bottom,
+    };
+
+    match client
+        .bulk_move_cards(&selection, &args.list_id, placement, args.selection.concurrency())
+        .await
+    {
+        Ok(report) => {
+            let json = serde_json::to_string_pretty(&report).unwrap_or_default();
+            ToolCallResult::text(json)
+        }
+        Err(e) => ToolCallResult::error(format!("Failed to move cards: {e}")),
+    }
+}
+
+#[derive(Deserialize)]
+struct BulkDeleteCardsArgs {
+    #[serde(flatten)]
+    selection: SelectionArgs,
+    expected_count: Option<usize>,
+}
+
+async fn bulk_delete_cards(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: BulkDeleteCardsArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required argument: card_ids or filter"),
+    };
+    let selection = match args.selection.selection() {
+        Ok(s) => s,
+        Err(e) => return ToolCallResult::error(e),
+    };
+
+    match client.bulk_delete_cards(&selection, args.expected_count, args.selection.concurrency()).await {
+        Ok(report) => {
+            let json = serde_json::to_string_pretty(&report).unwrap_or_default();
+            ToolCallResult::text(json)
+        }
+        Err(e) => ToolCallResult::error(format!("Failed to delete cards: {e}")),
+    }
+}
+
 #[derive(Deserialize)]
 struct DeleteListArgs {
     list_id: String,
@@ -587,6 +2224,32 @@ async fn delete_list(client: &PlankaClient, args: Option<Value>) -> ToolCallResu
     }
 }
 
+#[derive(Deserialize)]
+struct BatchArgs {
+    operations: Vec<batch::BatchStep>,
+}
+
+async fn batch(client: &PlankaClient, args: Option<Value>) -> ToolCallResult {
+    let args: BatchArgs = match args {
+        Some(v) => match serde_json::from_value(v) {
+            Ok(a) => a,
+            Err(e) => return ToolCallResult::error(format!("Invalid arguments: {e}")),
+        },
+        None => return ToolCallResult::error("Missing required argument: operations"),
+    };
+    if let Err(e) = batch::validate(&args.operations) {
+        return ToolCallResult::error(e);
+    }
+
+    let report = batch::run(client, args.operations).await;
+    let json = serde_json::to_string_pretty(&report).unwrap_or_default();
+    if report.ok {
+        ToolCallResult::text(json)
+    } else {
+        ToolCallResult::error(json)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -594,20 +2257,59 @@ mod tests {
     #[test]
     fn test_list_tools_returns_all_tools() {
         let tools = list_tools();
-        assert_eq!(tools.len(), 11, "Expected 11 tools");
+        assert_eq!(tools.len(), 37, "Expected 37 tools");
 
         let names: Vec<&str> = tools.iter().map(|t| t.name.as_str()).collect();
         assert!(names.contains(&"list_projects"));
         assert!(names.contains(&"list_boards"));
         assert!(names.contains(&"list_lists"));
         assert!(names.contains(&"list_cards"));
+        assert!(names.contains(&"get_board"));
+        assert!(names.contains(&"export_board"));
+        assert!(names.contains(&"create_project"));
+        assert!(names.contains(&"update_project"));
+        assert!(names.contains(&"delete_project"));
+        assert!(names.contains(&"backup_project"));
+        assert!(names.contains(&"restore_project"));
+        assert!(names.contains(&"add_project_manager"));
+        assert!(names.contains(&"remove_project_manager"));
         assert!(names.contains(&"create_board"));
+        assert!(names.contains(&"clone_board"));
+        assert!(names.contains(&"update_board"));
+        assert!(names.contains(&"delete_board"));
+        assert!(names.contains(&"list_board_members"));
+        assert!(names.contains(&"add_board_member"));
+        assert!(names.contains(&"update_board_member"));
+        assert!(names.contains(&"remove_board_member"));
         assert!(names.contains(&"create_list"));
+        assert!(names.contains(&"update_list"));
+        assert!(names.contains(&"reorder_lists"));
+        assert!(names.contains(&"apply_board_spec"));
         assert!(names.contains(&"create_card"));
+        assert!(names.contains(&"duplicate_card"));
+        assert!(names.contains(&"import_cards"));
+        assert!(names.contains(&"bulk_update_cards"));
+        assert!(names.contains(&"bulk_move_cards"));
+        assert!(names.contains(&"bulk_delete_cards"));
+        assert!(names.contains(&"batch"));
         assert!(names.contains(&"update_card"));
         assert!(names.contains(&"move_card"));
         assert!(names.contains(&"delete_card"));
         assert!(names.contains(&"delete_list"));
+        assert!(names.contains(&"list_instances"));
+    }
+
+    #[test]
+    fn test_instance_argument_on_planka_tools() {
+        for tool in list_tools() {
+            let has_instance = tool.input_schema["properties"].get("instance").is_some();
+            assert_eq!(
+                has_instance,
+                tool.name != "list_instances",
+                "{} has unexpected instance argument",
+                tool.name
+            );
+        }
     }
 
     #[test]
@@ -618,11 +2320,30 @@ mod tests {
             "list_boards",
             "list_lists",
             "list_cards",
+            "get_board",
+            "export_board",
+            "create_project",
+            "update_project",
+            "backup_project",
+            "restore_project",
+            "add_project_manager",
             "create_board",
+            "clone_board",
+            "update_board",
+            "list_board_members",
+            "add_board_member",
+            "update_board_member",
             "create_list",
+            "update_list",
+            "reorder_lists",
             "create_card",
+            "duplicate_card",
+            "import_cards",
             "update_card",
+            "bulk_update_cards",
             "move_card",
+            "bulk_move_cards",
+            "list_instances",
         ];
 
         for tool_name in programmatic_tools {
@@ -645,7 +2366,17 @@ mod tests {
     #[test]
     fn test_delete_tools_excluded_from_programmatic_calling() {
         let tools = list_tools();
-        let delete_tools = ["delete_card", "delete_list"];
+        let delete_tools = [
+            "delete_card",
+            "bulk_delete_cards",
+            "delete_list",
+            "delete_project",
+            "remove_project_manager",
+            "delete_board",
+            "remove_board_member",
+            "apply_board_spec",
+            "batch",
+        ];
 
         for tool_name in delete_tools {
             let tool = tools.iter().find(|t| t.name == tool_name).unwrap();
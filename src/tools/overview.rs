@@ -0,0 +1,151 @@
+use serde::Serialize;
+use std::fmt::Write;
+use tracing::debug;
+
+use crate::planka::types::{BoardResponse, Card, CardType, List};
+
+/// A board grouped by list, each in `position` order
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardOverview {
+    pub id: String,
+    pub name: String,
+    pub card_count: usize,
+    pub lists: Vec<ListOverview>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListOverview {
+    pub id: String,
+    pub name: String,
+    pub card_count: usize,
+    pub cards: Vec<CardSummary>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardSummary {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub card_type: CardType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_due_completed: Option<bool>,
+}
+
+impl From<&Card> for CardSummary {
+    fn from(card: &Card) -> Self {
+        Self {
+            id: card.id.clone(),
+            name: card.name.clone(),
+            card_type: card.card_type.clone(),
+            due_date: card.due_date.clone(),
+            is_due_completed: card.due_date.as_ref().and(card.is_due_completed),
+        }
+    }
+}
+
+/// Sort key placing items without a position last
+fn position_key(position: Option<f64>) -> f64 {
+    position.unwrap_or(f64::MAX)
+}
+
+/// Lists in position order, each with its cards in position order. Shared by
+/// the overview and the exports so both show a board the same way.
+pub(super) fn lists_in_order(data: &BoardResponse) -> Vec<(&List, Vec<&Card>)> {
+    let mut lists: Vec<&List> = data.included.lists.iter().collect();
+    lists.sort_by(|a, b| position_key(a.position).total_cmp(&position_key(b.position)));
+    lists
+        .into_iter()
+        .map(|list| {
+            let mut cards: Vec<&Card> = data.included.cards.iter().filter(|c| c.list_id == list.id).collect();
+            cards.sort_by(|a, b| position_key(a.position).total_cmp(&position_key(b.position)));
+            (list, cards)
+        })
+        .collect()
+}
+
+impl BoardOverview {
+    pub fn new(data: &BoardResponse) -> Self {
+        let lists: Vec<ListOverview> = lists_in_order(data)
+            .into_iter()
+            .map(|(list, cards)| ListOverview {
+                id: list.id.clone(),
+                name: list.name.clone(),
+                card_count: cards.len(),
+                cards: cards.into_iter().map(CardSummary::from).collect(),
+            })
+            .collect();
+
+        let card_count = lists.iter().map(|l| l.card_count).sum();
+        if card_count < data.included.cards.len() {
+            debug!(
+                board_id = %data.item.id,
+                skipped = data.included.cards.len() - card_count,
+                "Cards in lists not included in the board response were skipped"
+            );
+        }
+
+        Self {
+            id: data.item.id.clone(),
+            name: data.item.name.clone(),
+            card_count,
+            lists,
+        }
+    }
+
+    /// Compact Markdown rendering with one heading per list and one line per card
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "# {} (board {}, {} cards)", self.name, self.id, self.card_count);
+        for list in &self.lists {
+            let _ = writeln!(out, "\n## {} (list {}, {} cards)", list.name, list.id, list.card_count);
+            if list.cards.is_empty() {
+                let _ = writeln!(out, "_empty_");
+            }
+            for card in &list.cards {
+                let _ = write!(out, "- {} `{}`", card.name, card.id);
+                if matches!(card.card_type, CardType::Story) {
+                    let _ = write!(out, " [story]");
+                }
+                if let Some(due) = &card.due_date {
+                    let done = if card.is_due_completed == Some(true) { ", done" } else { "" };
+                    let _ = write!(out, " (due {due}{done})");
+                }
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::planka::test_support::BoardBuilder;
+    use serde_json::json;
+
+    #[test]
+    fn test_overview_orders_lists_and_cards_by_position() {
+        let first = json!({"type": "story", "dueDate": "2026-01-01T00:00:00Z", "isDueDateCompleted": true});
+        let data = BoardBuilder::new("Sprint")
+            .list("b", "Done", Some(2.0))
+            .list("a", "Todo", Some(1.0))
+            .card("2", "a", "Second", Some(20.0))
+            .card_with("1", "a", "First", Some(10.0), first)
+            .build();
+
+        let overview = BoardOverview::new(&data);
+        assert_eq!(overview.card_count, 2);
+        assert_eq!(overview.lists[0].name, "Todo");
+        assert_eq!(overview.lists[0].cards[0].name, "First");
+        assert_eq!(overview.lists[1].card_count, 0);
+
+        let markdown = overview.to_markdown();
+        assert!(markdown.contains("- First `1` [story] (due 2026-01-01T00:00:00Z, done)\n- Second `2`\n"));
+        assert!(markdown.contains("## Done (list b, 0 cards)\n_empty_"));
+    }
+}
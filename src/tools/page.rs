@@ -0,0 +1,157 @@
+use serde_json::json;
+use std::cmp::Ordering;
+
+use crate::mcp::types::Tool;
+use crate::planka::types::{Board, Card, List, Project};
+
+/// Items returned per page when no `limit` is given
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+/// Largest accepted `limit`
+pub const MAX_PAGE_SIZE: usize = 1000;
+
+/// Adds the `limit` and `cursor` arguments to a listing tool's input schema
+pub fn with_page_args(mut tool: Tool) -> Tool {
+    if let Some(properties) = tool.input_schema["properties"].as_object_mut() {
+        properties.insert(
+            "limit".to_string(),
+            json!({
+                "type": "integer",
+                "minimum": 1,
+                "maximum": MAX_PAGE_SIZE,
+                "description": format!("Maximum number of items to return (default: {DEFAULT_PAGE_SIZE})")
+            }),
+        );
+        properties.insert(
+            "cursor".to_string(),
+            json!({
+                "type": "string",
+                "description": "nextCursor from a previous call, to fetch the following page"
+            }),
+        );
+    }
+    tool
+}
+
+/// Items that can be paged. The API returns them in no fixed order, so they
+/// are sorted by position, then id, to keep offsets stable between calls.
+pub trait PageOrder {
+    fn page_position(&self) -> Option<f64>;
+    fn page_id(&self) -> &str;
+}
+
+impl PageOrder for Project {
+    fn page_position(&self) -> Option<f64> {
+        None
+    }
+    fn page_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl PageOrder for Board {
+    fn page_position(&self) -> Option<f64> {
+        self.position
+    }
+    fn page_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl PageOrder for List {
+    fn page_position(&self) -> Option<f64> {
+        self.position
+    }
+    fn page_id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl PageOrder for Card {
+    fn page_position(&self) -> Option<f64> {
+        self.position
+    }
+    fn page_id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// Items without a position come last; ids are numeric strings, so shorter ones are smaller
+fn page_order<T: PageOrder>(a: &T, b: &T) -> Ordering {
+    let position = match (a.page_position(), b.page_position()) {
+        (Some(a), Some(b)) => a.total_cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    };
+    position
+        .then_with(|| a.page_id().len().cmp(&b.page_id().len()))
+        .then_with(|| a.page_id().cmp(b.page_id()))
+}
+
+/// Sorts `items` into page order and cuts one page out of them. Returns the
+/// page and, when more items remain, the cursor for the next one.
+///
+/// Cursors are opaque to callers; they encode the offset of the next item.
+pub fn paginate<T: PageOrder>(
+    mut items: Vec<T>,
+    limit: Option<usize>,
+    cursor: Option<&str>,
+) -> Result<(Vec<T>, Option<String>), String> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+    let offset = match cursor {
+        Some(cursor) => decode_cursor(cursor).ok_or_else(|| format!("Invalid cursor: {cursor}"))?,
+        None => 0,
+    };
+    if offset > items.len() {
+        return Err(format!("Invalid cursor: {}", cursor.unwrap_or_default()));
+    }
+
+    items.sort_by(page_order);
+    let end = offset.saturating_add(limit).min(items.len());
+    let next = (end < items.len()).then(|| encode_cursor(end));
+    items.truncate(end);
+    items.drain(..offset);
+    Ok((items, next))
+}
+
+fn encode_cursor(offset: usize) -> String {
+    format!("o{offset:x}")
+}
+
+fn decode_cursor(cursor: &str) -> Option<usize> {
+    usize::from_str_radix(cursor.strip_prefix('o')?, 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(id: &str, position: Option<f64>) -> List {
+        serde_json::from_value(json!({"id": id, "boardId": "1", "name": id, "position": position})).unwrap()
+    }
+
+    fn ids(lists: &[List]) -> Vec<&str> {
+        lists.iter().map(|l| l.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_paginate_walks_all_items() {
+        let items = vec![
+            list("9", None),
+            list("12", Some(65535.0)),
+            list("10", Some(65535.0)),
+            list("11", Some(131070.0)),
+            list("3", Some(65535.0)),
+        ];
+        let (page, next) = paginate(items.clone(), Some(2), None).unwrap();
+        assert_eq!(ids(&page), vec!["3", "10"]);
+        let (page, next) = paginate(items.clone(), Some(2), next.as_deref()).unwrap();
+        assert_eq!(ids(&page), vec!["12", "11"]);
+        let (page, next) = paginate(items.clone(), Some(2), next.as_deref()).unwrap();
+        assert_eq!(ids(&page), vec!["9"]);
+        assert!(next.is_none());
+
+        assert!(paginate(items.clone(), None, Some("bogus")).is_err());
+        assert!(paginate(items, None, Some("o10")).is_err());
+    }
+}
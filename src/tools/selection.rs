@@ -0,0 +1,67 @@
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::mcp::types::Tool;
+use crate::planka::bulk::{CardFilter, CardSelection, DEFAULT_BULK_CONCURRENCY, MAX_BULK_CONCURRENCY};
+
+/// Card selection arguments shared by the bulk tools
+#[derive(Deserialize)]
+pub struct SelectionArgs {
+    card_ids: Option<Vec<String>>,
+    filter: Option<CardFilter>,
+    concurrency: Option<usize>,
+}
+
+impl SelectionArgs {
+    /// The selected cards; exactly one of `card_ids` and `filter` must be given
+    pub fn selection(&self) -> Result<CardSelection, String> {
+        match (&self.card_ids, &self.filter) {
+            (Some(ids), None) if ids.is_empty() => Err("card_ids must not be empty".to_string()),
+            (Some(ids), None) => Ok(CardSelection::Ids(ids.clone())),
+            (None, Some(filter)) => Ok(CardSelection::Filter(filter.clone())),
+            _ => Err("Use exactly one of card_ids and filter".to_string()),
+        }
+    }
+
+    pub fn concurrency(&self) -> usize {
+        self.concurrency.unwrap_or(DEFAULT_BULK_CONCURRENCY)
+    }
+}
+
+/// Adds the `card_ids`, `filter` and `concurrency` arguments to a bulk tool's input schema
+pub fn with_selection_args(mut tool: Tool) -> Tool {
+    if let Some(properties) = tool.input_schema["properties"].as_object_mut() {
+        properties.insert(
+            "card_ids".to_string(),
+            json!({
+                "type": "array",
+                "items": {"type": "string"},
+                "description": "IDs of the cards to change (or use filter)"
+            }),
+        );
+        properties.insert(
+            "filter".to_string(),
+            json!({
+                "type": "object",
+                "properties": {
+                    "board_id": {"type": "string", "description": "Board to select cards from"},
+                    "list": {"type": "string", "description": "List ID or name (optional)"},
+                    "label": {"type": "string", "description": "Label ID or name (optional)"},
+                    "name_contains": {"type": "string", "description": "Case-insensitive part of the card name (optional)"}
+                },
+                "required": ["board_id"],
+                "description": "Select the cards on a board matching all given conditions (or use card_ids)"
+            }),
+        );
+        properties.insert(
+            "concurrency".to_string(),
+            json!({
+                "type": "integer",
+                "minimum": 1,
+                "maximum": MAX_BULK_CONCURRENCY,
+                "description": format!("Cards changed at once (default: {DEFAULT_BULK_CONCURRENCY})")
+            }),
+        );
+    }
+    tool
+}